@@ -0,0 +1,402 @@
+//! A local store of confirmed answers, used to check days still give the same results.
+//!
+//! Answers are kept in a TOML file, keyed by day and by a hash of the input they belong to, so
+//! answers for different inputs (the examples, or someone else's input) can live side by side:
+//!
+//! ```toml
+//! [day05.3f1c8a9e0b7d6c54]
+//! part1 = "35"
+//! part2 = "46"
+//! ```
+//!
+//! Answers the website rejected are kept too, so they are never submitted twice:
+//!
+//! ```toml
+//! [day05.3f1c8a9e0b7d6c54.part2_rejected]
+//! wrong = ["40", "52"]
+//! too_low = "40"
+//! too_high = "52"
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use num::BigInt;
+use serde::{Deserialize, Serialize};
+
+use crate::solution::Answer;
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    /// No answers may be submitted before this time, in seconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    throttled_until: Option<u64>,
+    #[serde(flatten)]
+    days: BTreeMap<String, BTreeMap<String, PartAnswers>>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct PartAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+    #[serde(default, skip_serializing_if = "Rejected::is_empty")]
+    part1_rejected: Rejected,
+    #[serde(default, skip_serializing_if = "Rejected::is_empty")]
+    part2_rejected: Rejected,
+}
+
+/// Answers the website rejected for one part, and the bounds they imply.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Rejected {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    wrong: Vec<String>,
+    /// The highest answer known to be too low.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    too_low: Option<String>,
+    /// The lowest answer known to be too high.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    too_high: Option<String>,
+}
+
+/// How a freshly computed answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+/// Why the website rejected an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+/// Why an answer should not be submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// This answer was already accepted.
+    AlreadyAccepted,
+    /// A different answer was already accepted.
+    Accepted(String),
+    /// This exact answer was rejected before.
+    KnownWrong,
+    /// The answer is at least as high as one that was too high.
+    TooHigh(String),
+    /// The answer is at most as low as one that was too low.
+    TooLow(String),
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Invalid(PathBuf, toml::de::Error),
+}
+
+impl Answers {
+    /// Load the answers from `path`. A missing file is treated as an empty store.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|e| AnswersError::Invalid(path.to_path_buf(), e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(AnswersError::Io(path.to_path_buf(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let content = toml::to_string(self).expect("Answers can always be serialized");
+        fs::write(path, content).map_err(|e| AnswersError::Io(path.to_path_buf(), e))
+    }
+
+    fn part(&self, day: u8, input: &str, part: u8) -> Option<(&Option<String>, &Rejected)> {
+        let parts = self.days.get(&day_key(day))?.get(&input_hash(input))?;
+        Some(match part {
+            1 => (&parts.part1, &parts.part1_rejected),
+            _ => (&parts.part2, &parts.part2_rejected),
+        })
+    }
+
+    fn part_mut(&mut self, day: u8, input: &str, part: u8) -> (&mut Option<String>, &mut Rejected) {
+        let parts = self
+            .days
+            .entry(day_key(day))
+            .or_default()
+            .entry(input_hash(input))
+            .or_default();
+        match part {
+            1 => (&mut parts.part1, &mut parts.part1_rejected),
+            _ => (&mut parts.part2, &mut parts.part2_rejected),
+        }
+    }
+
+    /// The recorded answer for a part (1 or 2) of a day's input.
+    pub fn get(&self, day: u8, input: &str, part: u8) -> Option<&str> {
+        self.part(day, input, part)?.0.as_deref()
+    }
+
+    /// Record the answer for a part (1 or 2) of a day's input, replacing any previous answer.
+    pub fn record(&mut self, day: u8, input: &str, part: u8, answer: &Answer) {
+        *self.part_mut(day, input, part).0 = Some(answer.to_string());
+    }
+
+    /// Record that the website rejected an answer, narrowing down the bounds if it said why.
+    pub fn reject(&mut self, day: u8, input: &str, part: u8, answer: &Answer, why: Rejection) {
+        let rejected = self.part_mut(day, input, part).1;
+        let answer = answer.to_string();
+        let bound = match why {
+            Rejection::TooHigh => Some((&mut rejected.too_high, true)),
+            Rejection::TooLow => Some((&mut rejected.too_low, false)),
+            Rejection::Wrong => None,
+        };
+        if let Some((bound, lowest)) = bound {
+            let tighter = match bound.as_deref().and_then(number).zip(number(&answer)) {
+                Some((old, new)) => (new < old) == lowest,
+                None => true,
+            };
+            if tighter {
+                *bound = Some(answer.clone());
+            }
+        }
+        if !rejected.wrong.contains(&answer) {
+            rejected.wrong.push(answer);
+        }
+    }
+
+    /// Check whether an answer is worth submitting, given what is known about the part.
+    pub fn check_submission(
+        &self,
+        day: u8,
+        input: &str,
+        part: u8,
+        answer: &Answer,
+    ) -> Result<(), Refusal> {
+        let Some((accepted, rejected)) = self.part(day, input, part) else {
+            return Ok(());
+        };
+        let answer = answer.to_string();
+        match accepted {
+            Some(accepted) if *accepted == answer => return Err(Refusal::AlreadyAccepted),
+            Some(accepted) => return Err(Refusal::Accepted(accepted.clone())),
+            None => (),
+        }
+        if rejected.wrong.contains(&answer) {
+            return Err(Refusal::KnownWrong);
+        }
+        let Some(value) = number(&answer) else {
+            return Ok(());
+        };
+        if let Some(high) = &rejected.too_high {
+            if number(high).is_some_and(|high| value >= high) {
+                return Err(Refusal::TooHigh(high.clone()));
+            }
+        }
+        if let Some(low) = &rejected.too_low {
+            if number(low).is_some_and(|low| value <= low) {
+                return Err(Refusal::TooLow(low.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Don't allow submitting anything until `until`.
+    pub fn throttle(&mut self, until: SystemTime) {
+        let secs = until.duration_since(UNIX_EPOCH).unwrap_or_default();
+        // Round up, so the wait is never cut short.
+        self.throttled_until = Some(secs.as_secs() + u64::from(secs.subsec_nanos() > 0));
+    }
+
+    /// How much longer to wait before submitting, if at all.
+    pub fn throttled(&self, now: SystemTime) -> Option<Duration> {
+        let until = UNIX_EPOCH + Duration::from_secs(self.throttled_until?);
+        until
+            .duration_since(now)
+            .ok()
+            .filter(|wait| !wait.is_zero())
+    }
+
+    pub fn verify(&self, day: u8, input: &str, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, input, part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+fn number(answer: &str) -> Option<BigInt> {
+    answer.parse().ok()
+}
+
+impl Rejected {
+    fn is_empty(&self) -> bool {
+        self.wrong.is_empty() && self.too_low.is_none() && self.too_high.is_none()
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+/// A short, stable fingerprint of an input. Trailing whitespace is ignored, so a missing final
+/// newline doesn't change the hash.
+///
+/// This is 64-bit FNV-1a rather than `DefaultHasher`, whose output may change between Rust
+/// releases and would orphan every recorded answer.
+pub fn input_hash(input: &str) -> String {
+    let hash = input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        });
+    format!("{hash:016x}")
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadyAccepted => write!(f, "this answer was already accepted"),
+            Refusal::Accepted(answer) => write!(f, "{answer} was already accepted"),
+            Refusal::KnownWrong => write!(f, "this answer was already rejected"),
+            Refusal::TooHigh(bound) => write!(f, "{bound} was already too high"),
+            Refusal::TooLow(bound) => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(path, err) => write!(f, "Could not access {}: {err}", path.display()),
+            AnswersError::Invalid(path, err) => {
+                write!(f, "Invalid answers file {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_eq!(input_hash("1 2 3\n"), input_hash("1 2 3"));
+        assert_ne!(input_hash("1 2 3"), input_hash("1 2 4"));
+    }
+
+    #[test]
+    fn test_record_and_verify() {
+        let mut answers = Answers::default();
+        answers.record(5, "seeds: 79", 1, &Answer::from(35u32));
+        assert_eq!(answers.get(5, "seeds: 79", 1), Some("35"));
+        assert_eq!(
+            answers.verify(5, "seeds: 79", 1, &Answer::from(35u32)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.verify(5, "seeds: 79", 1, &Answer::from(36u32)),
+            Verdict::Fail {
+                expected: "35".to_string()
+            }
+        );
+        assert_eq!(
+            answers.verify(5, "seeds: 79", 2, &Answer::from(46u32)),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            answers.verify(5, "seeds: 80", 1, &Answer::from(35u32)),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            answers.verify(6, "seeds: 79", 1, &Answer::from(35u32)),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn test_check_submission() {
+        let mut answers = Answers::default();
+        let input = "seeds: 79";
+        let check = |answers: &Answers, n: u32| answers.check_submission(5, input, 1, &n.into());
+        assert_eq!(check(&answers, 35), Ok(()));
+
+        answers.reject(5, input, 1, &Answer::from(50u32), Rejection::TooHigh);
+        answers.reject(5, input, 1, &Answer::from(60u32), Rejection::TooHigh);
+        answers.reject(5, input, 1, &Answer::from(20u32), Rejection::TooLow);
+        answers.reject(5, input, 1, &Answer::from(30u32), Rejection::Wrong);
+        assert_eq!(check(&answers, 30), Err(Refusal::KnownWrong));
+        assert_eq!(check(&answers, 55), Err(Refusal::TooHigh("50".to_string())));
+        assert_eq!(check(&answers, 10), Err(Refusal::TooLow("20".to_string())));
+        assert_eq!(check(&answers, 35), Ok(()));
+        assert_eq!(
+            answers.check_submission(5, input, 1, &Answer::from("abc")),
+            Ok(())
+        );
+        assert_eq!(answers.get(5, input, 1), None);
+
+        answers.record(5, input, 1, &Answer::from(35u32));
+        assert_eq!(check(&answers, 35), Err(Refusal::AlreadyAccepted));
+        assert_eq!(
+            check(&answers, 36),
+            Err(Refusal::Accepted("35".to_string()))
+        );
+        assert_eq!(answers.check_submission(5, input, 2, &35u32.into()), Ok(()));
+    }
+
+    #[test]
+    fn test_throttle() {
+        let mut answers = Answers::default();
+        let now = UNIX_EPOCH + Duration::from_secs(1_000);
+        assert_eq!(answers.throttled(now), None);
+        answers.throttle(now + Duration::from_secs(60));
+        assert_eq!(answers.throttled(now), Some(Duration::from_secs(60)));
+        assert_eq!(answers.throttled(now + Duration::from_secs(60)), None);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        assert_eq!(Answers::load(&path).unwrap(), Answers::default());
+
+        let mut answers = Answers::default();
+        answers.record(5, "seeds: 79", 1, &Answer::from(35u32));
+        answers.record(5, "seeds: 79", 2, &Answer::from("abc"));
+        answers.record(12, "???.###", 1, &Answer::from(21u32));
+        answers.reject(12, "???.###", 2, &Answer::from(9u32), Rejection::TooLow);
+        answers.throttle(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        answers.save(&path).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains(&format!("[day05.{}]", input_hash("seeds: 79"))));
+        assert_eq!(Answers::load(&path).unwrap(), answers);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,269 @@
+//! Timing parse, part 1 and part 2 of each day over repeated runs.
+//!
+//! Reports are saved as JSON so a run can be compared against an earlier baseline.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::parsing::ParseError;
+use crate::solution::AnySolution;
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+/// Median and 95th percentile of a set of timings, in nanoseconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub day: u8,
+    pub parse: Timing,
+    pub part1: Timing,
+    pub part2: Timing,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayTimings>,
+}
+
+#[derive(Debug)]
+pub enum ReportError {
+    Io(PathBuf, io::Error),
+    Invalid(PathBuf, serde_json::Error),
+}
+
+impl Timing {
+    /// Summarize a set of samples. The 95th percentile uses the nearest-rank method, so with
+    /// few samples it is simply the slowest one.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Timing::default();
+        }
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+        let n = nanos.len();
+        let median_ns = if n % 2 == 1 {
+            nanos[n / 2]
+        } else {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2
+        };
+        let p95_ns = nanos[(n * 95).div_ceil(100) - 1];
+        Timing { median_ns, p95_ns }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn p95(&self) -> Duration {
+        Duration::from_nanos(self.p95_ns)
+    }
+}
+
+/// Time a day on `input`, running parse and both parts `iterations` times each.
+pub fn bench_day(
+    day: u8,
+    solution: &dyn AnySolution,
+    input: &str,
+    iterations: usize,
+) -> Result<DayTimings, ParseError> {
+    let iterations = iterations.max(1);
+    let mut parse = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = solution.prepare(input)?;
+        parse.push(start.elapsed());
+        drop(parsed);
+    }
+
+    let parsed = solution.prepare(input)?;
+    let part1 = time(iterations, || parsed.part1());
+    let part2 = time(iterations, || parsed.part2());
+    Ok(DayTimings {
+        day,
+        parse: Timing::from_samples(&parse),
+        part1: Timing::from_samples(&part1),
+        part2: Timing::from_samples(&part2),
+    })
+}
+
+fn time<T>(iterations: usize, f: impl Fn() -> T) -> Vec<Duration> {
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            let result = f();
+            let elapsed = start.elapsed();
+            drop(result);
+            elapsed
+        })
+        .collect()
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self, ReportError> {
+        let content =
+            fs::read_to_string(path).map_err(|e| ReportError::Io(path.to_path_buf(), e))?;
+        serde_json::from_str(&content).map_err(|e| ReportError::Invalid(path.to_path_buf(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ReportError> {
+        let content = serde_json::to_string_pretty(self).expect("Reports can always be serialized");
+        fs::write(path, content + "\n").map_err(|e| ReportError::Io(path.to_path_buf(), e))
+    }
+
+    pub fn get(&self, day: u8) -> Option<&DayTimings> {
+        self.days.iter().find(|timings| timings.day == day)
+    }
+}
+
+/// The relative change from `baseline` to `current`, e.g. `+12%` when it got slower.
+pub fn change(baseline: &Timing, current: &Timing) -> String {
+    if baseline.median_ns == 0 {
+        return "n/a".to_string();
+    }
+    let ratio = current.median_ns as f64 / baseline.median_ns as f64;
+    format!("{:+.0}%", (ratio - 1.0) * 100.0)
+}
+
+/// Format a duration with a unit that keeps it short, e.g. `850ns`, `12.3µs` or `1.25s`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..=999 => format!("{nanos}ns"),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let timing = format!(
+            "{} / {}",
+            format_duration(self.median()),
+            format_duration(self.p95())
+        );
+        f.pad(&timing)
+    }
+}
+
+impl fmt::Display for ReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportError::Io(path, err) => write!(f, "Could not access {}: {err}", path.display()),
+            ReportError::Invalid(path, err) => {
+                write!(f, "Invalid benchmark report {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReportError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Answer, Solution};
+
+    fn ms(samples: &[u64]) -> Vec<Duration> {
+        samples
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect()
+    }
+
+    #[test]
+    fn test_timing_from_samples() {
+        let timing = Timing::from_samples(&ms(&[5, 1, 3]));
+        assert_eq!(timing.median(), Duration::from_millis(3));
+        assert_eq!(timing.p95(), Duration::from_millis(5));
+
+        let timing = Timing::from_samples(&ms(&[4, 1, 3, 2]));
+        assert_eq!(timing.median(), Duration::from_micros(2500));
+
+        let samples: Vec<u64> = (1..=100).collect();
+        let timing = Timing::from_samples(&ms(&samples));
+        assert_eq!(timing.p95(), Duration::from_millis(95));
+
+        assert_eq!(Timing::from_samples(&[]), Timing::default());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(850)), "850ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.6ms");
+        assert_eq!(format_duration(Duration::from_millis(1_250)), "1.25s");
+    }
+
+    #[test]
+    fn test_change() {
+        let timing = |median_ns| Timing {
+            median_ns,
+            p95_ns: median_ns,
+        };
+        assert_eq!(change(&timing(100), &timing(112)), "+12%");
+        assert_eq!(change(&timing(100), &timing(50)), "-50%");
+        assert_eq!(change(&timing(0), &timing(50)), "n/a");
+    }
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            crate::parsing::number_list(input)
+        }
+
+        fn part1(&self, input: &Self::Input) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+
+        fn part2(&self, input: &Self::Input) -> Answer {
+            input.iter().product::<u32>().into()
+        }
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let timings = bench_day(3, &Sum, "1 2 3", 5).unwrap();
+        assert_eq!(timings.day, 3);
+        assert!(timings.parse.median() <= timings.parse.p95());
+        assert!(bench_day(3, &Sum, "1 x 3", 5).is_err());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        let timing = Timing {
+            median_ns: 1_000,
+            p95_ns: 2_000,
+        };
+        let report = Report {
+            iterations: 3,
+            days: vec![DayTimings {
+                day: 6,
+                parse: timing,
+                part1: timing,
+                part2: timing,
+            }],
+        };
+        report.save(&path).unwrap();
+        let loaded = Report::load(&path).unwrap();
+        assert_eq!(loaded, report);
+        assert_eq!(loaded.get(6).map(|t| t.day), Some(6));
+        assert!(loaded.get(7).is_none());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,416 @@
+//! Talking to the Advent of Code website: downloading puzzle inputs into the inputs directory
+//! and submitting answers.
+//!
+//! The base URL is configurable (`AOC_BASE_URL`) so the client can be pointed at a local
+//! stand-in server. The session token is the `session` cookie of a logged in browser, read from
+//! `AOC_SESSION`.
+
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::answers::Rejection;
+use crate::input;
+use crate::solution::Answer;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2023";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = "github.com/zedutch/aoc-2023";
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+/// Whether [`fetch_input`] had to download the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// What the website made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub outcome: Outcome,
+    /// How long to wait before submitting again, if the website said so.
+    pub wait: Option<Duration>,
+    /// The website's message, without markup.
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect(Rejection),
+    /// An answer was submitted too recently, this one wasn't checked.
+    RateLimited,
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    Unrecognized,
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session token was configured.
+    NoSession,
+    /// The server answered with an error status.
+    Status(u16, String),
+    /// The server could not be reached.
+    Transport(String),
+    Io(PathBuf, io::Error),
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// A client configured from the environment: `AOC_SESSION` and, optionally, `AOC_BASE_URL`.
+    /// A missing session token is only reported once a request needs it.
+    pub fn from_env() -> Self {
+        let session = env::var(SESSION_ENV).unwrap_or_default();
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Client::new(&base_url, &session)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> Result<String, ClientError> {
+        if self.session.is_empty() {
+            return Err(ClientError::NoSession);
+        }
+        Ok(format!("session={}", self.session))
+    }
+
+    /// Download the puzzle input for a day.
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&self.url(&format!("day/{day}/input")))
+            .set("Cookie", &self.cookie()?)
+            .call()
+            .map_err(ClientError::from)?;
+        response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string()))
+    }
+
+    /// Submit the answer to a part (1 or 2) of a day.
+    pub fn submit(&self, day: u8, part: u8, answer: &Answer) -> Result<Submission, ClientError> {
+        let response = self
+            .agent
+            .post(&self.url(&format!("day/{day}/answer")))
+            .set("Cookie", &self.cookie()?)
+            .send_form(&[
+                ("level", &part.to_string()),
+                ("answer", &answer.to_string()),
+            ])
+            .map_err(ClientError::from)?;
+        let page = response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string()))?;
+        Ok(parse_submission(&page))
+    }
+}
+
+lazy_static! {
+    static ref ARTICLE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref LEFT_TO_WAIT: Regex =
+        Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    static ref WAIT_MINUTES: Regex =
+        Regex::new(r"wait (one|\d+) minutes? before trying again").unwrap();
+}
+
+/// Read the outcome of a submission from the page the website answered with.
+pub fn parse_submission(page: &str) -> Submission {
+    let article = ARTICLE
+        .captures(page)
+        .map_or(page, |c| c.get(1).unwrap().as_str());
+    let message = TAG.replace_all(article, "");
+    let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let outcome = if message.contains("That's the right answer") {
+        Outcome::Correct
+    } else if message.contains("That's not the right answer") {
+        Outcome::Incorrect(if message.contains("your answer is too high") {
+            Rejection::TooHigh
+        } else if message.contains("your answer is too low") {
+            Rejection::TooLow
+        } else {
+            Rejection::Wrong
+        })
+    } else if message.contains("You gave an answer too recently") {
+        Outcome::RateLimited
+    } else if message.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unrecognized
+    };
+
+    let wait = if let Some(c) = LEFT_TO_WAIT.captures(&message) {
+        let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = c[2].parse().unwrap();
+        Some(Duration::from_secs(minutes * 60 + seconds))
+    } else {
+        WAIT_MINUTES.captures(&message).map(|c| {
+            let minutes = c[1].parse().unwrap_or(1);
+            Duration::from_secs(minutes * 60)
+        })
+    };
+
+    Submission {
+        outcome,
+        wait,
+        message,
+    }
+}
+
+/// Make sure the input for `day` is in the inputs directory, downloading it only if it isn't
+/// there yet. Returns where the input is stored.
+pub fn fetch_input(
+    client: &Client,
+    dir: &Path,
+    day: u8,
+) -> Result<(PathBuf, Fetched), ClientError> {
+    let path = input::day_path(dir, day);
+    if path.exists() {
+        return Ok((path, Fetched::Cached));
+    }
+    let content = client.input(day)?;
+    fs::create_dir_all(dir).map_err(|e| ClientError::Io(dir.to_path_buf(), e))?;
+    fs::write(&path, content).map_err(|e| ClientError::Io(path.clone(), e))?;
+    Ok((path, Fetched::Downloaded))
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(status, response) => {
+                ClientError::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(err) => ClientError::Transport(err.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "No session token found. Set {SESSION_ENV} to the `session` cookie of a logged in browser."
+            ),
+            ClientError::Status(400 | 401, _) => {
+                write!(f, "The server rejected the session token, is {SESSION_ENV} still valid?")
+            }
+            ClientError::Status(404, _) => write!(f, "Not found, is the puzzle unlocked yet?"),
+            ClientError::Status(status, body) => {
+                write!(f, "The server answered {status}: {}", body.trim())
+            }
+            ClientError::Transport(err) => write!(f, "Could not reach the server: {err}"),
+            ClientError::Io(path, err) => write!(f, "Could not write {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::*;
+
+    /// A request as received by the [`stub_server`].
+    #[derive(Debug)]
+    struct Request {
+        line: String,
+        headers: Vec<String>,
+        body: String,
+    }
+
+    /// Start a server on localhost that answers one request per response in `responses`, in
+    /// order. Returns its base URL and a channel receiving the requests it got.
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/2023", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut headers = Vec::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    headers.push(header.trim().to_string());
+                }
+                let length = headers
+                    .iter()
+                    .find_map(|h| {
+                        h.to_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(|n| n.trim().parse().unwrap())
+                    })
+                    .unwrap_or(0);
+                let mut request_body = vec![0; length];
+                io::Read::read_exact(&mut reader, &mut request_body).unwrap();
+                sender
+                    .send(Request {
+                        line: line.trim().to_string(),
+                        headers,
+                        body: String::from_utf8(request_body).unwrap(),
+                    })
+                    .unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (url, receiver)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-client-{name}-{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (url, requests) = stub_server(vec![(200, "1 2 3\n")]);
+        let client = Client::new(&url, "abc123");
+        let dir = temp_dir("fetch");
+
+        let (path, fetched) = fetch_input(&client, &dir, 9).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded);
+        assert_eq!(path, dir.join("day09.in"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "GET /2023/day/9/input HTTP/1.1");
+        assert!(request
+            .headers
+            .contains(&"Cookie: session=abc123".to_string()));
+
+        // The stub only answers once, so this must not touch the network.
+        assert_eq!(
+            fetch_input(&client, &dir, 9).unwrap(),
+            (path, Fetched::Cached)
+        );
+        // Cached inputs don't need a session either.
+        assert_eq!(
+            fetch_input(&Client::new(&url, ""), &dir, 9).unwrap().1,
+            Fetched::Cached
+        );
+        assert!(matches!(
+            fetch_input(&Client::new(&url, ""), &dir, 10),
+            Err(ClientError::NoSession)
+        ));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_error() {
+        let (url, _requests) = stub_server(vec![(404, "Not unlocked")]);
+        let client = Client::new(&url, "abc123");
+        let dir = temp_dir("error");
+
+        let err = fetch_input(&client, &dir, 25).unwrap_err();
+        assert!(matches!(err, ClientError::Status(404, _)));
+        assert!(!input::day_path(&dir, 25).exists());
+    }
+
+    const CORRECT: &str = r#"<html><body><main>
+<article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer to
+restoring snow operations. <a href="/2023/day/5#part2">[Continue to Part Two]</a></p></article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_parse_submission() {
+        let submission = parse_submission(CORRECT);
+        assert_eq!(submission.outcome, Outcome::Correct);
+        assert_eq!(submission.wait, None);
+        assert!(submission
+            .message
+            .starts_with("That's the right answer! You are one gold star"));
+
+        let too_high = parse_submission(
+            "<article><p>That's not the right answer; your answer is too high.  If you're stuck, \
+             make sure you're using the full input data. Please wait one minute before trying \
+             again. <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article>",
+        );
+        assert_eq!(too_high.outcome, Outcome::Incorrect(Rejection::TooHigh));
+        assert_eq!(too_high.wait, Some(Duration::from_secs(60)));
+
+        let too_low = parse_submission(
+            "<article><p>That's not the right answer; your answer is too low. Please wait 5 \
+             minutes before trying again.</p></article>",
+        );
+        assert_eq!(too_low.outcome, Outcome::Incorrect(Rejection::TooLow));
+        assert_eq!(too_low.wait, Some(Duration::from_secs(300)));
+
+        let wrong = parse_submission("<article><p>That's not the right answer.</p></article>");
+        assert_eq!(wrong.outcome, Outcome::Incorrect(Rejection::Wrong));
+
+        let limited = parse_submission(
+            "<article><p>You gave an answer too recently; you have to wait after submitting an \
+             answer before trying again.  You have 1m 5s left to wait.</p></article>",
+        );
+        assert_eq!(limited.outcome, Outcome::RateLimited);
+        assert_eq!(limited.wait, Some(Duration::from_secs(65)));
+
+        let level = parse_submission(
+            "<article><p>You don't seem to be solving the right level.  Did you already complete \
+             it?</p></article>",
+        );
+        assert_eq!(level.outcome, Outcome::WrongLevel);
+        assert_eq!(
+            parse_submission("<html></html>").outcome,
+            Outcome::Unrecognized
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, requests) = stub_server(vec![(200, CORRECT)]);
+        let client = Client::new(&url, "abc123");
+
+        let submission = client.submit(5, 2, &Answer::from(46u32)).unwrap();
+        assert_eq!(submission.outcome, Outcome::Correct);
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "POST /2023/day/5/answer HTTP/1.1");
+        assert!(request
+            .headers
+            .contains(&"Cookie: session=abc123".to_string()));
+        assert_eq!(request.body, "level=2&answer=46");
+    }
+}
@@ -0,0 +1,185 @@
+//! Detecting cycles in the states of a deterministic process, to skip ahead to a far-off state.
+//!
+//! The states are `initial`, `step(initial)`, `step(step(initial))`, ... numbered from 0. All
+//! functions assume the process eventually repeats a state, or they never return.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Where the states of a process start repeating: after `start` states, every `period` states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The number of the first state that equals state `n`, which is always below
+    /// `start + period`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Find the cycle by remembering every state. The fastest variant, but it keeps all states up to
+/// the end of the first period in memory.
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S) -> Cycle {
+    history(initial, step, usize::MAX).1
+}
+
+/// Find the cycle with Brent's algorithm, keeping only two states in memory. This runs `step`
+/// about three times as often as [`find_cycle`], so `step` must be pure.
+pub fn find_cycle_brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the period: the tortoise waits at powers of two for the hare to catch up with it
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // Find the start: walk two states `period` apart until they meet
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, period }
+}
+
+/// Find the cycle by remembering a 64-bit hash of every state instead of the state itself, for
+/// big states. A matching hash is confirmed by recomputing the earlier state, so collisions
+/// can't give a wrong result, but `step` must be pure.
+pub fn find_cycle_hashed<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut state = initial.clone();
+    for n in 0.. {
+        let hash = fingerprint(&state);
+        let earlier = seen.entry(hash).or_default();
+        for &start in earlier.iter() {
+            let candidate = (0..start).fold(initial.clone(), |s, _| step(&s));
+            if candidate == state {
+                return Cycle {
+                    start,
+                    period: n - start,
+                };
+            }
+        }
+        earlier.push(n);
+        state = step(&state);
+    }
+    unreachable!("the states never repeat")
+}
+
+/// State `n`, skipping ahead using the cycle in the states once it is found.
+pub fn nth_state<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    let (mut states, cycle) = history(initial, step, n);
+    states.swap_remove(cycle.reduce(n))
+}
+
+/// All states up to the end of the first period, or up to state `limit` if that comes first.
+/// In that case, the cycle found is a dummy one that only reduces numbers up to `limit`.
+fn history<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    limit: usize,
+) -> (Vec<S>, Cycle) {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states = vec![initial];
+    loop {
+        let n = states.len() - 1;
+        let state = &states[n];
+        if let Some(&start) = seen.get(state) {
+            states.pop();
+            let period = n - start;
+            return (states, Cycle { start, period });
+        }
+        if n == limit {
+            let start = limit + 1;
+            return (states, Cycle { start, period: 1 });
+        }
+        seen.insert(state.clone(), n);
+        let next = step(state);
+        states.push(next);
+    }
+}
+
+fn fingerprint<S: Hash>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 6, 2, 3, ...
+    fn step(n: &u32) -> u32 {
+        if *n == 6 {
+            2
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let expected = Cycle {
+            start: 2,
+            period: 5,
+        };
+        assert_eq!(find_cycle(0, step), expected);
+        assert_eq!(find_cycle_brent(0, step), expected);
+        assert_eq!(find_cycle_hashed(0, step), expected);
+
+        let expected = Cycle {
+            start: 0,
+            period: 5,
+        };
+        assert_eq!(find_cycle(4, step), expected);
+        assert_eq!(find_cycle_brent(4, step), expected);
+        assert_eq!(find_cycle_hashed(4, step), expected);
+
+        let fixed = Cycle {
+            start: 0,
+            period: 1,
+        };
+        assert_eq!(find_cycle_brent(7, |n| *n), fixed);
+    }
+
+    #[test]
+    fn test_reduce() {
+        let cycle = find_cycle(0, step);
+        assert_eq!(cycle.reduce(1), 1);
+        assert_eq!(cycle.reduce(6), 6);
+        assert_eq!(cycle.reduce(7), 2);
+        assert_eq!(cycle.reduce(1_000_000_000), 5);
+    }
+
+    #[test]
+    fn test_nth_state() {
+        assert_eq!(nth_state(0, step, 0), 0);
+        assert_eq!(nth_state(0, step, 3), 3);
+        assert_eq!(nth_state(0, step, 7), 2);
+        assert_eq!(nth_state(0, step, 1_000_000_000), 5);
+    }
+}
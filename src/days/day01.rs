@@ -0,0 +1,173 @@
+#![allow(unused)]
+
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        match run1(input) {
+            Some(sum) => sum.into(),
+            None => "A line has no digits".into(),
+        }
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        match run2(input) {
+            Some(sum) => sum.into(),
+            None => "A line has no digits".into(),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(str::trim)
+        .map(|line| {
+            match line
+                .char_indices()
+                .find(|(_, c)| !c.is_ascii_alphanumeric())
+            {
+                _ if line.is_empty() => Err(ParseError::new(line, "a calibration value")),
+                Some((i, c)) => Err(ParseError::new(
+                    &line[i..i + c.len_utf8()],
+                    "a letter or digit",
+                )),
+                None => Ok(line.to_string()),
+            }
+        })
+        .collect()
+}
+
+fn run1(lines: &[String]) -> Option<u32> {
+    lines.iter().map(|line| get_value1(line)).sum()
+}
+
+/// The first and last digit of the line as a two-digit number, `None` if it has no digits.
+fn get_value1(line: &str) -> Option<u32> {
+    let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+    let first = digits.next()?;
+    let last = digits.next_back().unwrap_or(first);
+    Some(first * 10 + last)
+}
+
+fn run2(lines: &[String]) -> Option<u32> {
+    lines.iter().map(|line| get_value2(line)).sum()
+}
+
+fn replace_words(input: &str) -> String {
+    input
+        .replace("one", "o1e")
+        .replace("two", "t2o")
+        .replace("three", "t3e")
+        .replace("four", "f4r")
+        .replace("five", "f5e")
+        .replace("six", "s6x")
+        .replace("seven", "s7n")
+        .replace("eight", "e8t")
+        .replace("nine", "n9e")
+}
+
+fn get_value2(line: &str) -> Option<u32> {
+    get_value1(&replace_words(line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day1p1() {
+        assert_eq!(get_value1("1abc2"), Some(12));
+        assert_eq!(get_value1("pqr3stu8vwx"), Some(38));
+        assert_eq!(get_value1("a1b2c3d4e5f"), Some(15));
+        assert_eq!(get_value1("treb7uchet"), Some(77));
+        assert_eq!(
+            run1(
+                &parse_input(
+                    r#"1abc2
+                   pqr3stu8vwx
+                   a1b2c3d4e5f
+                   treb7uchet
+                "#
+                )
+                .unwrap()
+            ),
+            Some(142)
+        );
+    }
+
+    #[test]
+    fn test_day1p2() {
+        assert_eq!(get_value2("1abc2"), Some(12));
+        assert_eq!(get_value2("pqr3stu8vwx"), Some(38));
+        assert_eq!(get_value2("a1b2c3d4e5f"), Some(15));
+        assert_eq!(get_value2("treb7uchet"), Some(77));
+        assert_eq!(
+            run2(
+                &parse_input(
+                    r#"1abc2
+                   pqr3stu8vwx
+                   a1b2c3d4e5f
+                   treb7uchet
+                "#
+                )
+                .unwrap()
+            ),
+            Some(142)
+        );
+
+        assert_eq!(get_value2("two1nine"), Some(29));
+        assert_eq!(get_value2("eightwothree"), Some(83));
+        assert_eq!(get_value2("abcone2threexyz"), Some(13));
+        assert_eq!(get_value2("xtwone3four"), Some(24));
+        assert_eq!(get_value2("4nineeightseven2"), Some(42));
+        assert_eq!(get_value2("zoneight234"), Some(14));
+        assert_eq!(get_value2("7pqrstsixteen"), Some(76));
+        assert_eq!(
+            run2(
+                &parse_input(
+                    r#"two1nine
+                   eightwothree
+                   abcone2threexyz
+                   xtwone3four
+                   4nineeightseven2
+                   zoneight234
+                   7pqrstsixteen
+                 "#
+                )
+                .unwrap()
+            ),
+            Some(281)
+        );
+
+        assert_eq!(get_value2("oneight"), Some(18));
+        assert_eq!(get_value2("eighthree"), Some(83));
+        assert_eq!(get_value2("oneighthree"), Some(13));
+    }
+
+    #[test]
+    fn test_day1_no_digits() {
+        assert_eq!(get_value1("abc"), None);
+        assert_eq!(get_value2("two"), Some(22));
+        let lines = parse_input("1abc2\nabc").unwrap();
+        assert_eq!(run1(&lines), None);
+        assert_eq!(run2(&lines), None);
+    }
+
+    #[test]
+    fn test_day1_parse_error() {
+        let input = "1abc2\npqr3 stu8vwx";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+}
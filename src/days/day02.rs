@@ -0,0 +1,230 @@
+#![allow(unused)]
+
+use crate::parsing::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, PartialEq, Default)]
+struct Balls {
+    r: u32,
+    g: u32,
+    b: u32,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        let max = Balls {
+            r: 12,
+            g: 13,
+            b: 14,
+        };
+        run1(input, max).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    rounds: Vec<Balls>,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    input.trim().lines().map(parse_game).collect()
+}
+
+fn parse_game(line: &str) -> Result<Game, ParseError> {
+    let line = parsing::prefix(line.trim(), "Game ")?;
+    let (id, rounds) = parsing::split_once(line, ":")?;
+    let id = parsing::number(id)?;
+    let rounds = rounds
+        .split(';')
+        .map(|round| {
+            let mut balls = Balls::default();
+            for draw in round.split(',') {
+                let (num, col) = parsing::split_once(draw.trim(), " ")?;
+                let num = parsing::number::<u32>(num)?;
+                match col {
+                    "red" => balls.r += num,
+                    "green" => balls.g += num,
+                    "blue" => balls.b += num,
+                    _ => return Err(ParseError::new(col, "red, green or blue")),
+                }
+            }
+            Ok(balls)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Game { id, rounds })
+}
+
+fn run1(games: &[Game], max: Balls) -> u32 {
+    let mut sum = 0;
+    for game in games {
+        let value = get_max(game);
+        if value.r <= max.r && value.g <= max.g && value.b <= max.b {
+            sum += game.id;
+        }
+    }
+    sum
+}
+
+fn get_max(game: &Game) -> Balls {
+    let mut max = Balls::default();
+    for round in game.rounds.iter() {
+        if round.r > max.r {
+            max.r = round.r;
+        }
+        if round.g > max.g {
+            max.g = round.g;
+        }
+        if round.b > max.b {
+            max.b = round.b;
+        }
+    }
+    max
+}
+
+fn run2(games: &[Game]) -> u32 {
+    let mut sum = 0;
+    for game in games {
+        let power = get_power(game);
+        sum += power;
+    }
+    sum
+}
+
+fn get_power(game: &Game) -> u32 {
+    let max = get_max(game);
+    max.r * max.g * max.b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day2p1_max() {
+        assert_eq!(
+            get_max(&parse_game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap()),
+            Balls { r: 4, g: 2, b: 6 }
+        );
+        assert_eq!(
+            get_max(
+                &parse_game("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")
+                    .unwrap()
+            ),
+            Balls { r: 1, g: 3, b: 4 }
+        );
+        assert_eq!(
+            get_max(
+                &parse_game(
+                    "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+                )
+                .unwrap()
+            ),
+            Balls { r: 20, g: 13, b: 6 }
+        );
+        assert_eq!(
+            get_max(
+                &parse_game(
+                    "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red"
+                )
+                .unwrap()
+            ),
+            Balls { r: 14, g: 3, b: 15 }
+        );
+        assert_eq!(
+            get_max(&parse_game("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green").unwrap()),
+            Balls { r: 6, g: 3, b: 2 }
+        );
+    }
+
+    #[test]
+    fn test_day2p1_run() {
+        let input = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+        let sum = run1(
+            &parse_input(input).unwrap(),
+            Balls {
+                r: 12,
+                g: 13,
+                b: 14,
+            },
+        );
+        assert_eq!(sum, 8);
+    }
+
+    #[test]
+    fn test_day2p2_power() {
+        assert_eq!(
+            get_power(
+                &parse_game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap()
+            ),
+            48
+        );
+        assert_eq!(
+            get_power(
+                &parse_game("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")
+                    .unwrap()
+            ),
+            12
+        );
+        assert_eq!(
+            get_power(
+                &parse_game(
+                    "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+                )
+                .unwrap()
+            ),
+            1560
+        );
+        assert_eq!(
+            get_power(
+                &parse_game(
+                    "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red"
+                )
+                .unwrap()
+            ),
+            630
+        );
+        assert_eq!(
+            get_power(
+                &parse_game("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green").unwrap()
+            ),
+            36
+        );
+    }
+
+    #[test]
+    fn test_day2p2_run() {
+        let input = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+        assert_eq!(run2(&parse_input(input).unwrap()), 2286);
+    }
+
+    #[test]
+    fn test_day2_parse_error() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 purple";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!(
+            (err.line, err.column, err.found.as_str()),
+            (2, 19, "purple")
+        );
+    }
+}
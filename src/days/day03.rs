@@ -2,20 +2,36 @@
 
 use std::collections::HashMap;
 
-fn main() {
-    part2();
-}
+use crate::grid::Grid;
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
 
-fn part1() {
-    let input = include_str!("./day03.in");
-    let sum = run1(input);
-    println!("{sum}");
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
 }
 
-fn part2() {
-    let input = include_str!("./day03.in");
-    let sum = run2(input);
-    println!("{sum}");
+/// Check the schematic is a rectangle of digits, symbols and dots.
+fn parse_input(input: &str) -> Result<String, ParseError> {
+    Grid::parse_with(
+        input,
+        |c| c.is_ascii_graphic().then_some(c),
+        "a digit, symbol or .",
+    )?;
+    Ok(input.trim().to_string())
 }
 
 #[derive(Debug)]
@@ -27,9 +43,9 @@ struct Entry {
 
 fn build_map(input: &str) -> HashMap<usize, Vec<Entry>> {
     let mut map: HashMap<usize, Vec<Entry>> = HashMap::new();
+    let pattern = regex::Regex::new(r"\d+").unwrap();
     for (idx, line) in input.trim().lines().enumerate() {
         let line = line.trim();
-        let pattern = regex::Regex::new(r"\d+").unwrap();
         let numbers = pattern.find_iter(line);
         let mut entries = vec![];
         for num in numbers {
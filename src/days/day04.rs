@@ -0,0 +1,201 @@
+#![allow(unused)]
+
+use crate::parsing::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
+}
+
+#[derive(Debug)]
+pub struct Card {
+    winning: Vec<u32>,
+    mine: Vec<u32>,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Card>, ParseError> {
+    input.trim().lines().map(parse_card).collect()
+}
+
+fn parse_card(line: &str) -> Result<Card, ParseError> {
+    let line = parsing::prefix(line.trim(), "Card")?;
+    let (id, numbers) = parsing::split_once(line, ":")?;
+    parsing::number::<u32>(id.trim())?;
+    parse_numbers(numbers)
+}
+
+fn parse_numbers(numbers: &str) -> Result<Card, ParseError> {
+    let (winning, mine) = parsing::split_once(numbers, "|")?;
+    Ok(Card {
+        winning: parsing::number_list(winning)?,
+        mine: parsing::number_list(mine)?,
+    })
+}
+
+fn run1(cards: &[Card]) -> u32 {
+    let mut sum = 0;
+    for card in cards {
+        sum += calc_card(card);
+    }
+    sum
+}
+
+fn calc_card(card: &Card) -> u32 {
+    let mut score = 0;
+    for num in card.winning.iter() {
+        if card.mine.contains(num) {
+            score += match score {
+                0 => 1,
+                _ => score,
+            };
+        }
+    }
+    score
+}
+
+fn run2(cards: &[Card]) -> u32 {
+    // Pre-allocate enough space for all games
+    let game_count = cards.len();
+    let mut counts: Vec<u32> = Vec::with_capacity(game_count);
+    counts.resize(game_count, 1);
+
+    for (idx, card) in cards.iter().enumerate() {
+        let wins: usize = number_of_wins(card).try_into().unwrap();
+        for i in 1..=wins {
+            counts[idx + i] += counts[idx];
+        }
+    }
+
+    counts.into_iter().sum()
+}
+
+fn number_of_wins(card: &Card) -> u32 {
+    let mut wins = 0;
+    for num in card.winning.iter() {
+        if card.mine.contains(num) {
+            wins += 1;
+        }
+    }
+    wins
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day04p1_cards() {
+        assert_eq!(
+            calc_card(&parse_card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap()),
+            8
+        );
+        assert_eq!(
+            calc_card(&parse_card("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19").unwrap()),
+            2
+        );
+        assert_eq!(
+            calc_card(&parse_card("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1").unwrap()),
+            2
+        );
+        assert_eq!(
+            calc_card(&parse_card("Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83").unwrap()),
+            1
+        );
+        assert_eq!(
+            calc_card(&parse_card("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36").unwrap()),
+            0
+        );
+        assert_eq!(
+            calc_card(&parse_card("Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11").unwrap()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_day04p1_run() {
+        assert_eq!(
+            run1(
+                &parse_input(
+                    r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#
+                )
+                .unwrap()
+            ),
+            13
+        );
+    }
+
+    #[test]
+    fn test_day04p2_cards() {
+        assert_eq!(
+            number_of_wins(&parse_numbers("41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap()),
+            4
+        );
+        assert_eq!(
+            number_of_wins(&parse_numbers("13 32 20 16 61 | 61 30 68 82 17 32 24 19").unwrap()),
+            2
+        );
+        assert_eq!(
+            number_of_wins(&parse_numbers(" 1 21 53 59 44 | 69 82 63 72 16 21 14  1").unwrap()),
+            2
+        );
+        assert_eq!(
+            number_of_wins(&parse_numbers("41 92 73 84 69 | 59 84 76 51 58  5 54 83").unwrap()),
+            1
+        );
+        assert_eq!(
+            number_of_wins(&parse_numbers("87 83 26 28 32 | 88 30 70 12 93 22 82 36").unwrap()),
+            0
+        );
+        assert_eq!(
+            number_of_wins(&parse_numbers("31 18 13 56 72 | 74 77 10 23 35 67 36 11").unwrap()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_day04p2_run() {
+        assert_eq!(
+            run2(
+                &parse_input(
+                    r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#
+                )
+                .unwrap()
+            ),
+            30
+        );
+    }
+
+    #[test]
+    fn test_day04_parse_error() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 32 61 30";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 20, "`|`")
+        );
+    }
+}
@@ -0,0 +1,452 @@
+#![allow(unused)]
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use regex::Regex;
+
+use crate::parsing::{self, ParseError};
+use crate::ranges::RangeSet;
+use crate::solution::{Answer, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
+}
+fn run1(almanac: &Almanac) -> u64 {
+    almanac
+        .seeds
+        .iter()
+        // Get their locations...
+        .map(|seed| almanac.maps.convert("seed", "location", *seed).unwrap())
+        // ...and find the minimum of all these locations
+        .min()
+        .unwrap()
+}
+
+fn run2(almanac: &Almanac) -> u64 {
+    let locations = almanac
+        .maps
+        .convert_ranges("seed", "location", &almanac.seed_ranges);
+    locations.unwrap().min().unwrap()
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    /// The seeds read as (start, length) pairs, for part 2.
+    seed_ranges: RangeSet<u64>,
+    maps: Maps,
+}
+
+/// The almanac's maps, each converting values from one category into another.
+///
+/// There is at most one map from every category, so following the maps from a category gives a
+/// single chain, which may loop back on itself. That keeps the way to convert between two
+/// categories unambiguous.
+#[derive(Debug, Default)]
+struct Maps {
+    /// For every source category, its target category and the mappings to it.
+    stages: HashMap<String, (String, Vec<Mapping>)>,
+}
+
+#[derive(Debug)]
+struct Mapping {
+    src: u64,
+    dest: u64,
+    range: u64,
+}
+
+/// Why values can't be converted from one category into another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ChainError {
+    /// There is no map from this category, so the chain stops there.
+    Broken(String),
+    /// Following the maps leads back to this category.
+    Cycle(String),
+}
+
+impl Maps {
+    /// The mappings to apply one after the other to convert `from` values into `to` values.
+    fn chain(&self, from: &str, to: &str) -> Result<Vec<&[Mapping]>, ChainError> {
+        let mut chain = Vec::new();
+        let mut seen = HashSet::new();
+        let mut category = from;
+        while category != to {
+            if !seen.insert(category) {
+                return Err(ChainError::Cycle(category.to_string()));
+            }
+            let Some((target, mappings)) = self.stages.get(category) else {
+                return Err(ChainError::Broken(category.to_string()));
+            };
+            chain.push(mappings.as_slice());
+            category = target;
+        }
+        Ok(chain)
+    }
+
+    fn convert(&self, from: &str, to: &str, value: u64) -> Result<u64, ChainError> {
+        let chain = self.chain(from, to)?;
+        Ok(chain
+            .into_iter()
+            .fold(value, |value, mappings| get_value(mappings, value)))
+    }
+
+    fn convert_ranges(
+        &self,
+        from: &str,
+        to: &str,
+        values: &RangeSet<u64>,
+    ) -> Result<RangeSet<u64>, ChainError> {
+        let chain = self.chain(from, to)?;
+        Ok(chain.into_iter().fold(values.clone(), |values, mappings| {
+            get_values(mappings, &values)
+        }))
+    }
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainError::Broken(category) => write!(f, "there is no map from {category}"),
+            ChainError::Cycle(category) => write!(f, "the maps loop back to {category}"),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Almanac, ParseError> {
+    let input = input.trim();
+    let seeds = input.lines().next().unwrap_or_default();
+    let seeds = parsing::prefix(seeds, "seeds:")?;
+    let tokens: Vec<&str> = seeds.split_whitespace().collect();
+    let Some(last) = tokens.last() else {
+        return Err(ParseError::after(seeds, "some seeds"));
+    };
+    let seeds: Vec<u64> = parsing::number_list(seeds)?;
+    if tokens.len() % 2 == 1 {
+        return Err(ParseError::new(last, "seeds in start and length pairs"));
+    }
+    let seed_ranges = seeds
+        .chunks(2)
+        .zip(tokens.chunks(2))
+        .map(|(pair, tokens)| match pair[0].checked_add(pair[1]) {
+            _ if pair[1] == 0 => Err(ParseError::new(tokens[1], "a length of at least 1")),
+            Some(end) => Ok(pair[0]..end),
+            None => Err(ParseError::new(
+                tokens[1],
+                "a length that fits after its start",
+            )),
+        })
+        .collect::<Result<_, _>>()?;
+    let maps = build_maps(input)?;
+    if let Err(err) = maps.chain("seed", "location") {
+        return Err(ParseError::after(
+            input,
+            format!("maps from seed to location, but {err}"),
+        ));
+    }
+    Ok(Almanac {
+        seeds,
+        seed_ranges,
+        maps,
+    })
+}
+
+/// Read every `X-to-Y map:` section, skipping the seeds.
+fn build_maps(input: &str) -> Result<Maps, ParseError> {
+    let mut maps = Maps::default();
+    let sections = parsing::paragraphs(input).into_iter().skip(1);
+    // Several empty lines in a row leave empty sections behind
+    for section in sections.filter(|section| !section.is_empty()) {
+        let header = section[0];
+        let Some(name) = header.strip_suffix(" map:") else {
+            return Err(ParseError::new(header, "a header like `seed-to-soil map:`"));
+        };
+        let (from, to) = parsing::split_once(name, "-to-")?;
+        if maps.stages.contains_key(from) {
+            return Err(ParseError::new(
+                header,
+                format!("a single map from {from}, as every category converts into one other"),
+            ));
+        }
+        let mappings = build_map(&section[1..])?;
+        maps.stages
+            .insert(from.to_string(), (to.to_string(), mappings));
+    }
+    Ok(maps)
+}
+
+fn get_value(mappings: &[Mapping], key: u64) -> u64 {
+    for map in mappings {
+        if map.src <= key && key - map.src < map.range {
+            return key - map.src + map.dest;
+        }
+    }
+    key
+}
+
+/// Map all values in `keys` at once, splitting their ranges where they cross a mapping's bounds.
+fn get_values(mappings: &[Mapping], keys: &RangeSet<u64>) -> RangeSet<u64> {
+    let mut unmapped = keys.clone();
+    let mut mapped = RangeSet::new();
+    for map in mappings {
+        let src = RangeSet::from(map.src..map.src + map.range);
+        mapped = mapped.union(&unmapped.intersection(&src).translate(map.src, map.dest));
+        unmapped = unmapped.difference(&src);
+    }
+    mapped.union(&unmapped)
+}
+
+fn build_map(lines: &[&str]) -> Result<Vec<Mapping>, ParseError> {
+    let mut map = Vec::new();
+    for line in lines {
+        let numbers: Vec<u64> = parsing::number_list(line)?;
+        let [dest, src, range] = numbers[..] else {
+            return Err(ParseError::new(line, "a destination, source and range"));
+        };
+        if src.checked_add(range).is_none() || dest.checked_add(range).is_none() {
+            return Err(ParseError::new(
+                line,
+                "a range that fits after its source and destination",
+            ));
+        }
+        map.push(Mapping { src, dest, range })
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day05p1() {
+        let input = r#"seeds: 79 14 55 13
+
+            seed-to-soil map:
+            50 98 2
+            52 50 48
+
+            soil-to-fertilizer map:
+            0 15 37
+            37 52 2
+            39 0 15
+
+            fertilizer-to-water map:
+            49 53 8
+            0 11 42
+            42 0 7
+            57 7 4
+
+            water-to-light map:
+            88 18 7
+            18 25 70
+
+            light-to-temperature map:
+            45 77 23
+            81 45 19
+            68 64 13
+
+            temperature-to-humidity map:
+            0 69 1
+            1 0 69
+
+            humidity-to-location map:
+            60 56 37
+            56 93 4
+        "#;
+        let almanac = parse_input(input).unwrap();
+        let maps = &almanac.maps;
+        let seed_to_soil = |seed| maps.convert("seed", "soil", seed).unwrap();
+        let get_location = |seed| maps.convert("seed", "location", seed).unwrap();
+
+        assert_eq!(seed_to_soil(98), 50);
+        assert_eq!(seed_to_soil(99), 51);
+        assert_eq!(seed_to_soil(53), 55);
+        assert_eq!(seed_to_soil(10), 10);
+        assert_eq!(seed_to_soil(79), 81);
+        assert_eq!(seed_to_soil(14), 14);
+        assert_eq!(seed_to_soil(55), 57);
+        assert_eq!(seed_to_soil(13), 13);
+
+        assert_eq!(get_location(79), 82);
+        assert_eq!(get_location(14), 43);
+        assert_eq!(get_location(55), 86);
+        assert_eq!(get_location(13), 35);
+
+        assert_eq!(run1(&almanac), 35);
+    }
+
+    #[test]
+    fn test_day05p2() {
+        let input = r#"seeds: 79 14 55 13
+
+            seed-to-soil map:
+            50 98 2
+            52 50 48
+
+            soil-to-fertilizer map:
+            0 15 37
+            37 52 2
+            39 0 15
+
+            fertilizer-to-water map:
+            49 53 8
+            0 11 42
+            42 0 7
+            57 7 4
+
+            water-to-light map:
+            88 18 7
+            18 25 70
+
+            light-to-temperature map:
+            45 77 23
+            81 45 19
+            68 64 13
+
+            temperature-to-humidity map:
+            0 69 1
+            1 0 69
+
+            humidity-to-location map:
+            60 56 37
+            56 93 4
+        "#;
+        assert_eq!(run2(&parse_input(input).unwrap()), 46);
+    }
+
+    #[test]
+    fn test_day05p2_ranges() {
+        let mappings = vec![
+            Mapping {
+                src: 98,
+                dest: 50,
+                range: 2,
+            },
+            Mapping {
+                src: 50,
+                dest: 52,
+                range: 48,
+            },
+        ];
+        let keys: RangeSet<u64> = [40..60, 95..105].into_iter().collect();
+        let values: RangeSet<u64> = [40..50, 50..52, 52..62, 97..100, 100..105]
+            .into_iter()
+            .collect();
+        assert_eq!(get_values(&mappings, &keys), values);
+    }
+
+    #[test]
+    fn test_day05_large_numbers() {
+        let input = r#"seeds: 5000000000 10000000000
+
+            seed-to-location map:
+            1 5000000001 4294967296
+        "#;
+        let almanac = parse_input(input).unwrap();
+        assert_eq!(run1(&almanac), 5000000000);
+        assert_eq!(run2(&almanac), 1);
+    }
+
+    #[test]
+    fn test_day05_parse_error() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (5, 1, "52 50"));
+
+        let input = "seeds: 79 14 55\n\nseed-to-location map:\n50 98 2\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 14, "55"));
+        let input = "seeds: 79 18446744073709551600\n\nseed-to-location map:\n50 98 2\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (1, 11));
+        let input = "seeds: 79 0\n\nseed-to-location map:\n50 98 2\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 11, "0"));
+        let input = "seeds:\n\nseed-to-location map:\n50 98 2\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (1, 7));
+
+        let input = "seeds: 1 1\n\nseed-to-location map:\n0 18446744073709551610 10\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!(err.line, 4);
+        let input = "seeds: 1 1\n\nseed-to-location map:\n18446744073709551610 0 10\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!(err.line, 4);
+    }
+
+    #[test]
+    fn test_day05_chains() {
+        let input = r#"seeds: 1 1
+
+            b-to-c map:
+            100 0 10
+
+            a-to-b map:
+            10 0 10
+
+            seed-to-a map:
+            0 0 0
+
+            a-to-location map:
+            0 0 0
+        "#;
+        let err = parse_input(input).unwrap_err();
+        assert_eq!(
+            err.expected,
+            "a single map from a, as every category converts into one other"
+        );
+
+        let input = r#"seeds: 1 1
+
+            b-to-c map:
+            100 10 10
+
+            seed-to-b map:
+            10 0 10
+
+            c-to-location map:
+
+            location-to-b map:
+        "#;
+        let maps = parse_input(input).unwrap().maps;
+        assert_eq!(maps.convert("seed", "c", 5), Ok(105));
+        assert_eq!(maps.convert("b", "c", 5), Ok(5));
+        assert_eq!(maps.convert("c", "c", 5), Ok(5));
+        assert_eq!(maps.chain("c", "b").map(|chain| chain.len()), Ok(2));
+        assert_eq!(
+            maps.convert("water", "c", 5),
+            Err(ChainError::Broken("water".to_string()))
+        );
+        assert_eq!(
+            maps.convert("c", "water", 5),
+            Err(ChainError::Cycle("c".to_string()))
+        );
+
+        let input = "seeds: 1 1\n\n\n\nseed-to-location map:\n1 2 3\n\n\n";
+        let maps = parse_input(input).unwrap().maps;
+        assert_eq!(maps.convert("seed", "location", 2), Ok(1));
+
+        let input = "seeds: 1 1\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-seed map:\n4 5 6";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!(
+            err.expected,
+            "maps from seed to location, but the maps loop back to seed"
+        );
+        assert_eq!((err.line, err.column), (7, 6));
+    }
+}
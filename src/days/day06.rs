@@ -0,0 +1,239 @@
+#![allow(unused)]
+
+use crate::parsing::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        match run1(input) {
+            Some(product) => product.into(),
+            None => "The product of the ways to win doesn't fit in 128 bits".into(),
+        }
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
+}
+
+#[derive(Debug)]
+pub struct Races {
+    times: Vec<u128>,
+    records: Vec<u128>,
+    /// The times and records read as one number each, for part 2.
+    time: u128,
+    record: u128,
+}
+
+fn parse_input(input: &str) -> Result<Races, ParseError> {
+    let mut lines = input.trim().lines().map(str::trim);
+    let time = lines.next().unwrap_or_default();
+    let times = parsing::number_list(parsing::prefix(time, "Time:")?)?;
+    if times.is_empty() {
+        return Err(ParseError::after(time, "some times"));
+    }
+    let Some(record) = lines.next() else {
+        return Err(ParseError::after(input, "a line with distances"));
+    };
+    let records = parsing::number_list(parsing::prefix(record, "Distance:")?)?;
+    if records.len() != times.len() {
+        return Err(ParseError::new(
+            record,
+            format!("{} distances", times.len()),
+        ));
+    }
+    let Some(time) = join_digits(&times) else {
+        return Err(ParseError::new(
+            time,
+            "times that fit in 128 bits once joined",
+        ));
+    };
+    let Some(record) = join_digits(&records) else {
+        return Err(ParseError::new(
+            record,
+            "distances that fit in 128 bits once joined",
+        ));
+    };
+    Ok(Races {
+        times,
+        records,
+        time,
+        record,
+    })
+}
+
+/// The product of the ways to win each race, `None` if it doesn't fit in a u128.
+fn run1(races: &Races) -> Option<u128> {
+    let mut result: u128 = 1;
+    for entry in races.times.iter().zip(races.records.iter()) {
+        result = result.checked_mul(race(*entry.0, *entry.1))?;
+    }
+    Some(result)
+}
+
+fn run2(races: &Races) -> u128 {
+    race(races.time, races.record)
+}
+
+/// Read the numbers as one big number, ignoring the spaces between them. Returns `None` if it
+/// doesn't fit in a u128.
+fn join_digits(numbers: &[u128]) -> Option<u128> {
+    numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<String>()
+        .parse::<u128>()
+        .ok()
+}
+
+/// The amount of hold times `i` that beat the record, i.e. for which `i * (time - i) > record`.
+fn race(time: u128, record: u128) -> u128 {
+    // The distance is symmetric around `time / 2`, where it peaks, and grows up to there. So the
+    // winning hold times are `first..=time - first` for the first winning one.
+    if !beats(time / 2, time, record) {
+        return 0;
+    }
+    let first = first_win(time, record);
+    time - 2 * first + 1
+}
+
+/// The shortest hold time that beats the record, given that holding `time / 2` does.
+fn first_win(time: u128, record: u128) -> u128 {
+    // Holding `i` wins when `i² - time * i + record < 0`, so the first win is just above the
+    // smaller root `(time - √(time² - 4 * record)) / 2`.
+    let discriminant = time
+        .checked_mul(time)
+        .and_then(|square| square.checked_sub(record.checked_mul(4)?));
+    let Some(discriminant) = discriminant else {
+        // Too big to square: binary search the rising half instead
+        let (mut low, mut high) = (0, time / 2);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if beats(mid, time, record) {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        return low;
+    };
+    // The square root is rounded down, which can put the estimate one off either way
+    let mut first = (time - discriminant.isqrt()) / 2;
+    while first > 0 && beats(first - 1, time, record) {
+        first -= 1;
+    }
+    while !beats(first, time, record) {
+        first += 1;
+    }
+    first
+}
+
+/// Whether holding the button for `hold` beats the record.
+fn beats(hold: u128, time: u128, record: u128) -> bool {
+    // A distance too big for a u128 beats any record
+    hold.checked_mul(time - hold)
+        .is_none_or(|distance| distance > record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day06p1_races() {
+        assert_eq!(race(7, 9), 4);
+        assert_eq!(race(15, 40), 8);
+        assert_eq!(race(30, 200), 9);
+    }
+
+    #[test]
+    fn test_day06_race_edges() {
+        assert_eq!(race(0, 0), 0);
+        assert_eq!(race(1, 0), 0);
+        assert_eq!(race(2, 0), 1);
+        assert_eq!(race(2, 1), 0);
+        assert_eq!(race(10, 24), 1);
+        assert_eq!(race(10, 25), 0);
+        assert_eq!(race(71530, 940200), 71503);
+        // Times whose square doesn't fit in a u128
+        assert_eq!(race(u128::MAX, 0), u128::MAX - 1);
+        assert_eq!(race(u128::MAX, u128::MAX), u128::MAX - 3);
+        assert_eq!(race(1 << 100, u128::MAX), (1 << 100) - (1 << 29) - 1);
+    }
+
+    #[test]
+    fn test_day06_race_brute_force() {
+        let brute_force = |time: u128, record: u128| {
+            (0..=time).filter(|&i| i * (time - i) > record).count() as u128
+        };
+        // A fixed xorshift sequence, so failures can be reproduced
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut random = |max: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed % max
+        };
+        for _ in 0..1000 {
+            let time = u128::from(random(500));
+            let best = time * time / 4;
+            let record = u128::from(random(best as u64 + 2));
+            assert_eq!(
+                race(time, record),
+                brute_force(time, record),
+                "{time} {record}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_day06p1_run() {
+        let input = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+
+        assert_eq!(run1(&parse_input(input).unwrap()), Some(288));
+    }
+
+    #[test]
+    fn test_day06p2_run() {
+        let input = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+
+        assert_eq!(run2(&parse_input(input).unwrap()), 71503);
+    }
+
+    #[test]
+    fn test_day06_parse_error() {
+        let input = "Time:\nDistance:";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (1, 6));
+
+        let time = "1".repeat(20);
+        let input = format!("Time: {time} {time}\nDistance: 1 1");
+        let err = parse_input(&input).unwrap_err().locate(&input);
+        assert_eq!((err.line, err.column), (1, 1));
+        let input = format!("Time: 1 1\nDistance: {time} {time}");
+        let err = parse_input(&input).unwrap_err().locate(&input);
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_day06p1_overflow() {
+        // Parsed races can't overflow, their joined times are checked to fit
+        let races = Races {
+            times: vec![u128::MAX, u128::MAX],
+            records: vec![0, 0],
+            time: 0,
+            record: 0,
+        };
+        assert_eq!(run1(&races), None);
+    }
+}
@@ -5,25 +5,53 @@ use std::{
     collections::{BTreeMap, HashMap},
 };
 
-fn main() {
-    part2();
+use crate::parsing::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<(String, u32)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
 }
 
-fn part1() {
-    let input = include_str!("./day07.in");
-    let result = run1(input);
-    println!("{result}");
+fn parse_input(input: &str) -> Result<Vec<(String, u32)>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (hand, bid) = parsing::split_once(line.trim(), " ")?;
+            if hand.chars().count() != 5 {
+                return Err(ParseError::new(hand, "a hand of 5 cards"));
+            }
+            if let Some((i, c)) = hand
+                .char_indices()
+                .find(|(_, c)| !c.is_ascii_digit() && !"AKQJT".contains(*c))
+            {
+                return Err(ParseError::new(&hand[i..i + c.len_utf8()], "a card"));
+            }
+            Ok((hand.to_string(), parsing::number(bid.trim())?))
+        })
+        .collect()
 }
 
-fn run1(input: &str) -> u32 {
+fn run1(input: &[(String, u32)]) -> u32 {
     let mut bids: HashMap<&str, u32> = HashMap::new();
     let mut hands: Vec<&str> = Vec::new();
-    let total_ranks = input.trim().lines().count();
-    for line in input.trim().lines() {
-        let mut line = line.trim().split(' ');
-        let hand = line.next().unwrap();
-        let bid = line.next().unwrap();
-        bids.insert(hand, bid.parse().unwrap());
+    let total_ranks = input.len();
+    for (hand, bid) in input {
+        bids.insert(hand, *bid);
         hands.push(hand);
     }
     hands.sort_unstable_by(cmp_hands_pt1);
@@ -37,21 +65,12 @@ fn run1(input: &str) -> u32 {
     result
 }
 
-fn part2() {
-    let input = include_str!("./day07.in");
-    let result = run2(input);
-    println!("{result}");
-}
-
-fn run2(input: &str) -> u32 {
+fn run2(input: &[(String, u32)]) -> u32 {
     let mut bids: HashMap<&str, u32> = HashMap::new();
     let mut hands: Vec<&str> = Vec::new();
-    let total_ranks = input.trim().lines().count();
-    for line in input.trim().lines() {
-        let mut line = line.trim().split(' ');
-        let hand = line.next().unwrap();
-        let bid = line.next().unwrap();
-        bids.insert(hand, bid.parse().unwrap());
+    let total_ranks = input.len();
+    for (hand, bid) in input {
+        bids.insert(hand, *bid);
         hands.push(hand);
     }
     hands.sort_unstable_by(cmp_hands_pt2);
@@ -182,7 +201,7 @@ fn is_full_house_joker(hand: &&str) -> bool {
     match jokers {
         0 => is_full_house(hand),
         1 => is_two_pair(hand), // 2 pairs + joker = full house
-        _ => false, // 2 or more jokers can never lead to full house
+        _ => false,             // 2 or more jokers can never lead to full house
     }
 }
 
@@ -229,12 +248,15 @@ mod tests {
     fn test_day07p1_run() {
         assert_eq!(
             run1(
-                r#"32T3K 765
+                &parse_input(
+                    r#"32T3K 765
             T55J5 684
             KK677 28
             KTJJT 220
             QQQJA 483
         "#
+                )
+                .unwrap()
             ),
             6440
         );
@@ -242,11 +264,14 @@ mod tests {
         // Test full houses
         assert_eq!(
             run1(
-                r#"33322 1
+                &parse_input(
+                    r#"33322 1
             33232 10
             22333 100
             33344 1000
         "#
+                )
+                .unwrap()
             ),
             4123
         );
@@ -254,10 +279,13 @@ mod tests {
         // Test two pairs
         assert_eq!(
             run1(
-                r#"11222 1
+                &parse_input(
+                    r#"11222 1
             11223 10
             22134 100
         "#
+                )
+                .unwrap()
             ),
             123
         );
@@ -267,12 +295,15 @@ mod tests {
     fn test_day07p2_run() {
         assert_eq!(
             run2(
-                r#"32T3K 765
+                &parse_input(
+                    r#"32T3K 765
             T55J5 684
             KK677 28
             KTJJT 220
             QQQJA 483
         "#
+                )
+                .unwrap()
             ),
             5905
         );
@@ -282,11 +313,14 @@ mod tests {
     fn test_day07p2_full_house() {
         assert_eq!(
             run2(
-                r#"33J22 1
+                &parse_input(
+                    r#"33J22 1
             3323J 10
             22333 100
             33344 1000
         "#
+                )
+                .unwrap()
             ),
             3142
         );
@@ -296,12 +330,15 @@ mod tests {
     fn test_day07p2_two_pairs() {
         assert_eq!(
             run2(
-                r#"11222 1
+                &parse_input(
+                    r#"11222 1
             11223 10
             22134 100
             221J4 1000
             1122J 10000
         "#
+                )
+                .unwrap()
             ),
             43125
         );
@@ -311,12 +348,25 @@ mod tests {
     fn test_day07p2_five_of_a_kind() {
         assert_eq!(
             run2(
-                r#"JJJJJ 1
+                &parse_input(
+                    r#"JJJJJ 1
             QJJJJ 10
             JJJJQ 100
         "#
+                )
+                .unwrap()
             ),
             231
         );
     }
+
+    #[test]
+    fn test_day07_parse_error() {
+        let input = "32T3K 765\nT55X5 684";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 4, "a card")
+        );
+    }
 }
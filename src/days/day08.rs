@@ -0,0 +1,329 @@
+#![allow(unused)]
+
+use regex::Regex;
+
+use crate::math::crt;
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+use crate::symbols::Interner;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Vec<Turn>, Network);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        match run2(input) {
+            Some(steps) => steps.into(),
+            None => "The ghosts are never all on a Z node at once".into(),
+        }
+    }
+}
+
+/// The nodes of the map, numbered in the order they appear, with their left and right node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    names: Interner,
+    nodes: Vec<[u32; 2]>,
+}
+
+impl Network {
+    fn next(&self, node: u32, turn: Turn) -> u32 {
+        self.nodes[node as usize][turn as usize]
+    }
+
+    fn name(&self, node: u32) -> &str {
+        self.names.name(node)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Turn>, Network), ParseError> {
+    let mut names = Interner::new();
+    let mut nodes: Vec<Option<[u32; 2]>> = Vec::new();
+    // Where each node is first used, to point at it if it turns out to be missing
+    let mut references = Vec::new();
+    let mut lines = input.lines();
+    let first = lines.next().unwrap_or_default().trim();
+    let instructions = first
+        .char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            _ => Err(ParseError::new(&first[i..i + c.len_utf8()], "L or R")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if instructions.is_empty() {
+        return Err(ParseError::new(first, "at least one L or R"));
+    }
+    // Skip empty line
+    match lines.next() {
+        Some(line) if line.trim().is_empty() => (),
+        Some(line) => return Err(ParseError::new(line, "an empty line")),
+        None => return Err(ParseError::after(input, "an empty line")),
+    }
+
+    let pattern = Regex::new(r"^(\w+) = \((\w+), (\w+)\)$").unwrap();
+    for line in lines
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+    {
+        let Some((_, [s, l, r])) = pattern.captures(line).map(|c| c.extract()) else {
+            return Err(ParseError::new(line, "a node like `AAA = (BBB, CCC)`"));
+        };
+        let [s, l, r] = [s, l, r].map(|name| {
+            let id = names.intern(name);
+            if id as usize == nodes.len() {
+                nodes.push(None);
+                references.push(name);
+            }
+            id
+        });
+        nodes[s as usize] = Some([l, r]);
+    }
+
+    let nodes = nodes
+        .into_iter()
+        .zip(references)
+        .map(|(node, name)| node.ok_or_else(|| ParseError::new(name, "a node that is defined")))
+        .collect::<Result<_, _>>()?;
+    Ok((instructions, Network { names, nodes }))
+}
+
+fn run1((instructions, network): &(Vec<Turn>, Network)) -> u32 {
+    let (Some(start), Some(goal)) = (network.names.get("AAA"), network.names.get("ZZZ")) else {
+        return 0;
+    };
+    let mut steps = 0;
+    let mut curr = start;
+    for &instr in instructions.iter().cycle().take(instructions.len() * 100) {
+        curr = network.next(curr, instr);
+        steps += 1;
+        if curr == goal {
+            return steps;
+        }
+    }
+
+    0
+}
+
+fn run2((instructions, network): &(Vec<Turn>, Network)) -> Option<u64> {
+    let mut ghosts: Vec<Ghost> = network
+        .names
+        .iter()
+        .filter(|(_, name)| name.ends_with('A'))
+        .map(|(start, _)| Ghost::walk(start, instructions, network))
+        .collect();
+    ghosts.sort_by_key(|ghost| ghost.hits.len() + ghost.cycle_hits.len());
+    let (first, others) = ghosts.split_first()?;
+
+    // Before every ghost is in its cycle, try each step the first ghost is on a Z node
+    let prefix = ghosts.iter().map(|ghost| ghost.prefix).max()?;
+    let early = first.hits.iter().copied().chain(
+        first
+            .cycle_hits
+            .iter()
+            .flat_map(|&hit| (hit..prefix).step_by(first.period as usize)),
+    );
+    if let Some(steps) = early
+        .filter(|&steps| steps < prefix && others.iter().all(|ghost| ghost.hits_at(steps)))
+        .min()
+    {
+        return Some(steps);
+    }
+
+    // After that, every ghost is on a Z node at fixed offsets in its cycle
+    let mut congruences = vec![(0, 1)];
+    for ghost in &ghosts {
+        let period = i128::from(ghost.period);
+        congruences = congruences
+            .iter()
+            .flat_map(|&congruence| {
+                ghost
+                    .cycle_hits
+                    .iter()
+                    .filter_map(move |&hit| crt(congruence, (i128::from(hit), period)))
+            })
+            .collect();
+        congruences.sort_unstable();
+        congruences.dedup();
+    }
+    congruences
+        .into_iter()
+        .map(|(remainder, modulus)| {
+            // The first step from `prefix` on with this remainder
+            let prefix = i128::from(prefix);
+            prefix + (remainder - prefix).rem_euclid(modulus)
+        })
+        .min()
+        .map(|steps| steps as u64)
+}
+
+/// Where a ghost is on a Z node. Its state is the node it is on together with the index of the
+/// next instruction, so after `prefix` steps it goes through the same `period` states forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Ghost {
+    prefix: u64,
+    period: u64,
+    /// The steps before `prefix` after which the ghost is on a Z node.
+    hits: Vec<u64>,
+    /// The steps in the first round of the cycle after which the ghost is on a Z node.
+    cycle_hits: Vec<u64>,
+}
+
+impl Ghost {
+    fn walk(start: u32, instructions: &[Turn], network: &Network) -> Self {
+        // When each state was first seen, indexed by node and then by instruction
+        let mut seen: Vec<Option<u64>> = vec![None; network.nodes.len() * instructions.len()];
+        let mut z_steps = Vec::new();
+        let mut curr = start;
+        for steps in 0.. {
+            let index = steps as usize % instructions.len();
+            let state = curr as usize * instructions.len() + index;
+            if let Some(prefix) = seen[state] {
+                let (hits, cycle_hits) = z_steps.iter().partition(|&&hit| hit < prefix);
+                return Ghost {
+                    prefix,
+                    period: steps - prefix,
+                    hits,
+                    cycle_hits,
+                };
+            }
+            if network.name(curr).ends_with('Z') {
+                z_steps.push(steps);
+            }
+            seen[state] = Some(steps);
+            curr = network.next(curr, instructions[index]);
+        }
+        unreachable!("the ghost never repeats a state")
+    }
+
+    fn hits_at(&self, steps: u64) -> bool {
+        if steps < self.prefix {
+            self.hits.contains(&steps)
+        } else {
+            let offset = (steps - self.prefix) % self.period;
+            self.cycle_hits.contains(&(self.prefix + offset))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day08p1_run_1() {
+        let input = r#"RL
+
+            AAA = (BBB, CCC)
+            BBB = (DDD, EEE)
+            CCC = (ZZZ, GGG)
+            DDD = (DDD, DDD)
+            EEE = (EEE, EEE)
+            GGG = (GGG, GGG)
+            ZZZ = (ZZZ, ZZZ)"#;
+
+        assert_eq!(run1(&parse_input(input).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_day08p1_run_2() {
+        let input = r#"LLR
+
+            AAA = (BBB, BBB)
+            BBB = (AAA, ZZZ)
+            ZZZ = (ZZZ, ZZZ)"#;
+
+        assert_eq!(run1(&parse_input(input).unwrap()), 6);
+    }
+
+    #[test]
+    fn test_day08p2_run_1() {
+        let input = r#"LR
+
+            11A = (11B, XXX)
+            11B = (XXX, 11Z)
+            11Z = (11B, XXX)
+            22A = (22B, XXX)
+            22B = (22C, 22C)
+            22C = (22Z, 22Z)
+            22Z = (22B, 22B)
+            XXX = (XXX, XXX)"#;
+
+        assert_eq!(run2(&parse_input(input).unwrap()), Some(6));
+    }
+
+    /// Ghosts that only turn left, from their start through the given nodes
+    fn ghosts(paths: &[&str]) -> (Vec<Turn>, Network) {
+        let mut input = "L\n\n".to_string();
+        for path in paths {
+            let nodes: Vec<&str> = path.split(" -> ").collect();
+            for pair in nodes.windows(2) {
+                input += &format!("{0} = ({1}, {1})\n", pair[0], pair[1]);
+            }
+        }
+        parse_input(&input).unwrap()
+    }
+
+    #[test]
+    fn test_day08p2_offset_cycles() {
+        // Z after 3, 5, 7, ... steps and after 1, 4, 7, ... steps
+        let odd = "11A -> 11B -> 11C -> 11Z -> 11C";
+        let thirds = "22A -> 22Z -> 22B -> 22C -> 22Z";
+        let (instructions, network) = ghosts(&[thirds]);
+        let start = network.names.get("22A").unwrap();
+        let ghost = Ghost::walk(start, &instructions, &network);
+        assert_eq!((ghost.prefix, ghost.period), (1, 3));
+        assert_eq!((ghost.hits, ghost.cycle_hits), (vec![], vec![1]));
+        assert_eq!(run2(&ghosts(&[odd, thirds])), Some(7));
+    }
+
+    #[test]
+    fn test_day08p2_prefix_only() {
+        // Z only after 1 step
+        let once = "44A -> 44Z -> 44B -> 44B";
+        let thirds = "22A -> 22Z -> 22B -> 22C -> 22Z";
+        let odd = "11A -> 11B -> 11C -> 11Z -> 11C";
+        assert_eq!(run2(&ghosts(&[once, thirds])), Some(1));
+        assert_eq!(run2(&ghosts(&[once, odd])), None);
+    }
+
+    #[test]
+    fn test_day08p2_never() {
+        // Z after 3, 5, 7, ... steps and after 2, 4, 6, ... steps
+        let odd = "11A -> 11B -> 11C -> 11Z -> 11C";
+        let even = "33A -> 33B -> 33Z -> 33B";
+        assert_eq!(run2(&ghosts(&[odd, even])), None);
+    }
+
+    #[test]
+    fn test_day08_parse_error() {
+        let input = "LRX\n\nAAA = (BBB, CCC)";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 3, "X"));
+        let input = "\n\nAAA = (AAA, AAA)";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "at least one L or R");
+        let input = "LR\n\nAAA = (BBB, CCC)\nBBB = (DDD EEE)";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (4, 1));
+        let input = "LR\n\nAAA = (BBB, AAA)\nBBB = (AAA, CCC)";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (4, 13, "CCC"));
+    }
+}
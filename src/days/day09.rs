@@ -0,0 +1,163 @@
+#![allow(unused)]
+
+use num::{BigInt, One, Zero};
+
+use crate::parsing::{number_list, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| match number_list(line)? {
+            numbers if numbers.is_empty() => Err(ParseError::new(line, "a list of numbers")),
+            numbers => Ok(numbers),
+        })
+        .collect()
+}
+
+fn run1(input: &[Vec<i64>]) -> BigInt {
+    input.iter().map(|numbers| predict_next(numbers)).sum()
+}
+
+fn predict_next(numbers: &[i64]) -> BigInt {
+    Polynomial::fit(numbers).value_at(numbers.len() as i64)
+}
+
+fn run2(input: &[Vec<i64>]) -> BigInt {
+    input.iter().map(|numbers| predict_prev(numbers)).sum()
+}
+
+fn predict_prev(numbers: &[i64]) -> BigInt {
+    Polynomial::fit(numbers).value_at(-1)
+}
+
+/// The lowest degree polynomial going through a sequence of values, with the first value at
+/// index 0. It is stored in Newton form: the first value of each row of differences, so it can be
+/// evaluated exactly at any index, also before the start of the sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    differences: Vec<BigInt>,
+}
+
+impl Polynomial {
+    /// Fit the polynomial to `values`, taken at indices 0, 1, 2, ...
+    pub fn fit(values: &[i64]) -> Self {
+        let mut row: Vec<BigInt> = values.iter().map(|&v| BigInt::from(v)).collect();
+        let mut differences = Vec::with_capacity(row.len());
+        while let Some(first) = row.first() {
+            differences.push(first.clone());
+            row = row.windows(2).map(|pair| &pair[1] - &pair[0]).collect();
+        }
+        // Rows of zeroes don't add anything, and leaving them out makes the degree exact
+        while differences.last().is_some_and(Zero::is_zero) {
+            differences.pop();
+        }
+        Polynomial { differences }
+    }
+
+    /// The degree of the polynomial, `None` if it is zero everywhere. A degree one less than the
+    /// amount of values means every sequence of that length fits, so the prediction is a guess.
+    pub fn degree(&self) -> Option<usize> {
+        self.differences.len().checked_sub(1)
+    }
+
+    /// The value at index `n`: the sum of every difference times `n` choose its row.
+    pub fn value_at(&self, n: i64) -> BigInt {
+        let n = BigInt::from(n);
+        let mut value = BigInt::zero();
+        let mut binomial = BigInt::one();
+        for (k, difference) in self.differences.iter().enumerate() {
+            value += difference * &binomial;
+            // C(n, k + 1) = C(n, k) * (n - k) / (k + 1), which always divides evenly
+            binomial = binomial * (&n - k) / (k + 1);
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day09p1_run() {
+        let input = r#"0 3 6 9 12 15
+            1 3 6 10 15 21
+            10 13 16 21 30 45"#;
+        assert_eq!(run1(&parse_input(input).unwrap()), BigInt::from(114));
+    }
+
+    #[test]
+    fn test_day09p1_predict_next() {
+        assert_eq!(predict_next(&[0, 3, 6, 9, 12, 15]), BigInt::from(18));
+        assert_eq!(predict_next(&[1, 3, 6, 10, 15, 21]), BigInt::from(28));
+        assert_eq!(predict_next(&[10, 13, 16, 21, 30, 45]), BigInt::from(68));
+    }
+
+    #[test]
+    fn test_day09p2_run() {
+        let input = r#"0 3 6 9 12 15
+            1 3 6 10 15 21
+            10 13 16 21 30 45"#;
+        assert_eq!(run2(&parse_input(input).unwrap()), BigInt::from(2));
+    }
+
+    #[test]
+    fn test_day09p2_predict_prev() {
+        assert_eq!(predict_prev(&[0, 3, 6, 9, 12, 15]), BigInt::from(-3));
+        assert_eq!(predict_prev(&[1, 3, 6, 10, 15, 21]), BigInt::from(0));
+        assert_eq!(predict_prev(&[10, 13, 16, 21, 30, 45]), BigInt::from(5));
+    }
+
+    #[test]
+    fn test_day09_extrapolate_anywhere() {
+        // n² + 3n - 5, sampled at 0..5
+        let f = |n: i64| n * n + 3 * n - 5;
+        let values: Vec<i64> = (0..5).map(f).collect();
+        let polynomial = Polynomial::fit(&values);
+        assert_eq!(polynomial.degree(), Some(2));
+        for n in [-1000, -7, -1, 0, 4, 5, 123_456] {
+            assert_eq!(polynomial.value_at(n), BigInt::from(f(n)));
+        }
+    }
+
+    #[test]
+    fn test_day09_degree() {
+        assert_eq!(Polynomial::fit(&[0, 0, 0]).degree(), None);
+        assert_eq!(Polynomial::fit(&[7, 7, 7]).degree(), Some(0));
+        assert_eq!(Polynomial::fit(&[10, 13, 16, 21, 30, 45]).degree(), Some(3));
+        // Any two values fit a line, the only way to tell is that nothing was left over
+        assert_eq!(Polynomial::fit(&[1, 5]).degree(), Some(1));
+    }
+
+    #[test]
+    fn test_day09_no_overflow() {
+        let values = [i64::MIN, i64::MAX, i64::MIN, i64::MAX];
+        let polynomial = Polynomial::fit(&values);
+        assert_eq!(polynomial.degree(), Some(3));
+        for (n, &value) in values.iter().enumerate() {
+            assert_eq!(polynomial.value_at(n as i64), BigInt::from(value));
+        }
+        let next = predict_next(&values);
+        assert!(next > BigInt::from(i64::MAX));
+    }
+}
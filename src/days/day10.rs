@@ -0,0 +1,579 @@
+#![allow(unused)]
+
+use std::collections::HashSet;
+
+use crate::geometry::Coord;
+use crate::graph;
+use crate::grid::{FromChar, Grid};
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Pipes;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_pipes(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
+}
+
+fn run1(pipes: &Pipes) -> u64 {
+    let size = get_loop_size(pipes);
+    size.div_ceil(2)
+}
+
+fn run2(pipes: &Pipes) -> u64 {
+    get_inner_tiles(pipes)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum Pipe {
+    Start,
+    Horizontal,
+    Vertical,
+    TopRight,
+    TopLeft,
+    BottomRight,
+    BottomLeft,
+    None,
+
+    // Part 2
+    Outer,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct PipePiece {
+    pipe: Pipe,
+    x: i32,
+    y: i32,
+}
+
+impl FromChar for Pipe {
+    const EXPECTED: &'static str = "a pipe or .";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Pipe::None),
+            '|' => Some(Pipe::Vertical),
+            '-' => Some(Pipe::Horizontal),
+            '7' => Some(Pipe::BottomLeft),
+            'L' => Some(Pipe::TopRight),
+            'F' => Some(Pipe::BottomRight),
+            'J' => Some(Pipe::TopLeft),
+            'S' => Some(Pipe::Start),
+            _ => None,
+        }
+    }
+}
+
+type Pipes = Grid<Pipe>;
+
+fn parse_pipes(input: &str) -> Result<Pipes, ParseError> {
+    let pipes = Grid::parse(input)?;
+    if pipes.find(&Pipe::Start).is_none() {
+        return Err(ParseError::after(input.trim_end(), "a start tile `S`"));
+    }
+    Ok(pipes)
+}
+
+fn get_start_coords(pipes: &Pipes) -> (i32, i32) {
+    let (x, y) = pipes.find(&Pipe::Start).unwrap_or_default();
+    (i32::try_from(x).unwrap(), i32::try_from(y).unwrap())
+}
+
+fn get_pieces(pipes: &Pipes) -> HashSet<PipePiece> {
+    let mut coords = get_start_coords(pipes);
+    let mut pieces = HashSet::<PipePiece>::new();
+    pieces.insert(PipePiece {
+        pipe: Pipe::Start,
+        x: coords.0,
+        y: coords.1,
+    });
+    while let Some(next) = find_next(coords, &mut pieces, pipes) {
+        coords = next;
+    }
+    pieces
+}
+
+fn get_loop_size(pipes: &Pipes) -> u64 {
+    let pieces = get_pieces(pipes);
+    u64::try_from(pieces.len()).unwrap()
+}
+
+/// Get the amount of inner tiles surrounded by pipes.
+fn get_inner_tiles(pipes: &Pipes) -> u64 {
+    // First, parse the pipes and remove all pipes that are not part of the main path
+    let pieces = get_pieces(pipes);
+    let mut pipes = clean_pipes(pipes, &pieces);
+
+    // Then blow the pipes list up, 1 tile -> 3x3 tiles
+    let mut pipes = blow_up(&pipes);
+
+    // Flood fill the blown up tile map, starting from the border tiles
+    let (width, height) = (pipes.width() as i64, pipes.height() as i64);
+    let border: Vec<Coord> = pipes
+        .coords()
+        .filter(|&(x, y)| x == 0 || y == 0 || x == width - 1 || y == height - 1)
+        .collect();
+    for coord in border {
+        graph::flood_fill(&mut pipes, coord, &Pipe::Outer, |p| *p == Pipe::None);
+    }
+
+    // Shrink the filled map back down, 3x3 tiles -> 1 (centre) tile
+    let pipes = shrink_down(&pipes);
+
+    // Count the amount of empty tiles that we didn't visit in the flood fill
+    u64::try_from(pipes.count(&Pipe::None)).unwrap()
+}
+
+/// Blow up a map so each tile maps onto a 3x3 set of tiles.
+fn blow_up(pipes: &Pipes) -> Pipes {
+    let mut new = Grid::filled(pipes.width() * 3, pipes.height() * 3, Pipe::None);
+    for (coord, el) in pipes.iter() {
+        set_blown_up(&mut new, coord, el);
+    }
+    new
+}
+
+/// Set a value in a blown up map using its coordinates and value in the original map.
+fn set_blown_up(pipes: &mut Pipes, (column, row): Coord, el: &Pipe) {
+    // Set the tile at offset (x, y) within the 3x3 tiles
+    let mut set = |x: i64, y: i64, pipe: Pipe| pipes[(column * 3 + x, row * 3 + y)] = pipe;
+    // Set all elements to None
+    for i in 0..3 {
+        for j in 0..3 {
+            set(j, i, Pipe::None);
+        }
+    }
+    match el {
+        Pipe::Start => {
+            for i in 0..3 {
+                for j in 0..3 {
+                    set(j, i, Pipe::Start);
+                }
+            }
+        }
+        Pipe::Horizontal => {
+            for i in 0..3 {
+                set(i, 1, Pipe::Horizontal);
+            }
+        }
+        Pipe::Vertical => {
+            for i in 0..3 {
+                set(1, i, Pipe::Vertical);
+            }
+        }
+        Pipe::TopRight => {
+            set(1, 0, Pipe::Vertical);
+            set(1, 1, Pipe::TopRight);
+            set(2, 1, Pipe::Horizontal);
+        }
+        Pipe::TopLeft => {
+            set(1, 0, Pipe::Horizontal);
+            set(1, 1, Pipe::TopLeft);
+            set(0, 1, Pipe::Vertical);
+        }
+        Pipe::BottomRight => {
+            set(1, 2, Pipe::Vertical);
+            set(1, 1, Pipe::BottomRight);
+            set(2, 1, Pipe::Horizontal);
+        }
+        Pipe::BottomLeft => {
+            set(1, 2, Pipe::Vertical);
+            set(1, 1, Pipe::BottomLeft);
+            set(0, 1, Pipe::Horizontal);
+        }
+        Pipe::Outer | Pipe::None => (),
+    }
+}
+
+/// Shrink down a blown up map so each set of 3x3 tiles maps back to 1 tile.
+/// The shrunken down values are the centres of the 3x3 tiles.
+fn shrink_down(pipes: &Pipes) -> Pipes {
+    let mut new = Grid::filled(pipes.width() / 3, pipes.height() / 3, Pipe::None);
+    for ((x, y), el) in pipes.iter() {
+        if x % 3 == 1 && y % 3 == 1 {
+            new[(x / 3, y / 3)] = el.clone();
+        }
+    }
+    new
+}
+
+/// Remove all pipes from the map that are not part of the main loop
+fn clean_pipes(pipes: &Pipes, pieces: &HashSet<PipePiece>) -> Pipes {
+    let mut clean = pipes.clone();
+    for ((x, y), pipe) in pipes.iter() {
+        let piece = PipePiece {
+            pipe: pipe.clone(),
+            x: i32::try_from(x).unwrap(),
+            y: i32::try_from(y).unwrap(),
+        };
+        if !pieces.contains(&piece) {
+            clean[(x, y)] = Pipe::None;
+        }
+    }
+    clean
+}
+
+/// Find the next pipe that's part of the main loop
+fn find_next(
+    coords: (i32, i32),
+    pieces: &mut HashSet<PipePiece>,
+    pipes: &Pipes,
+) -> Option<(i32, i32)> {
+    let prev = &pipes[(i64::from(coords.0), i64::from(coords.1))];
+    let to_check: Vec<(i32, i32)> = match prev {
+        Pipe::Start => vec![(0, 1), (1, 0), (-1, 0), (0, -1)],
+        Pipe::Horizontal => vec![(-1, 0), (1, 0)],
+        Pipe::Vertical => vec![(0, 1), (0, -1)],
+        Pipe::TopRight => vec![(0, -1), (1, 0)],
+        Pipe::TopLeft => vec![(0, -1), (-1, 0)],
+        Pipe::BottomRight => vec![(0, 1), (1, 0)],
+        Pipe::BottomLeft => vec![(0, 1), (-1, 0)],
+        _ => panic!("Came from invalid tile"),
+    };
+
+    for delta in to_check {
+        let mut next = (coords.0 + delta.0, coords.1 + delta.1);
+        let pipe = pipes.get((i64::from(next.0), i64::from(next.1)));
+        let Some(pipe) = pipe else {
+            continue;
+        };
+        if pieces.contains(&PipePiece {
+            pipe: pipe.clone(),
+            x: next.0,
+            y: next.1,
+        }) {
+            // Pipe already parsed
+            continue;
+        }
+        match (delta, pipe) {
+            // DOWN
+            ((0, 1), Pipe::TopLeft) | ((0, 1), Pipe::TopRight) | ((0, 1), Pipe::Vertical) => {
+                if !matches!(
+                    prev,
+                    Pipe::Start | Pipe::Vertical | Pipe::BottomLeft | Pipe::BottomRight
+                ) {
+                    continue;
+                }
+            }
+            // UP
+            ((0, -1), Pipe::BottomLeft)
+            | ((0, -1), Pipe::BottomRight)
+            | ((0, -1), Pipe::Vertical) => {
+                if !matches!(
+                    prev,
+                    Pipe::Start | Pipe::Vertical | Pipe::TopLeft | Pipe::TopRight
+                ) {
+                    continue;
+                }
+            }
+            // RIGHT
+            ((1, 0), Pipe::TopLeft) | ((1, 0), Pipe::BottomLeft) | ((1, 0), Pipe::Horizontal) => {
+                if !matches!(
+                    prev,
+                    Pipe::Start | Pipe::Horizontal | Pipe::TopRight | Pipe::BottomRight
+                ) {
+                    continue;
+                }
+            }
+            // LEFT
+            ((-1, 0), Pipe::TopRight)
+            | ((-1, 0), Pipe::BottomRight)
+            | ((-1, 0), Pipe::Horizontal) => {
+                if !matches!(
+                    prev,
+                    Pipe::Start | Pipe::Horizontal | Pipe::TopLeft | Pipe::BottomLeft
+                ) {
+                    continue;
+                }
+            }
+            _ => continue,
+        };
+        pieces.insert(PipePiece {
+            pipe: pipe.clone(),
+            x: next.0,
+            y: next.1,
+        });
+        return Some(next);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day10p1_run_1() {
+        let input = r#"
+        .....
+        .S-7.
+        .|.|.
+        .L-J.
+        .....
+        "#;
+        assert_eq!(run1(&parse_pipes(input).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_day10p1_run_2() {
+        let input = r#"
+        -L|F7
+        7S-7|
+        L|7||
+        -L-J|
+        L|-JF
+        "#;
+        assert_eq!(run1(&parse_pipes(input).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_day10p1_run_3() {
+        let input = r#"
+        ..F7.
+        .FJ|.
+        SJ.L7
+        |F--J
+        LJ...
+        "#;
+        assert_eq!(run1(&parse_pipes(input).unwrap()), 8);
+    }
+
+    #[test]
+    fn test_day10p1_run_4() {
+        let input = r#"
+        7-F7-
+        .FJ|7
+        SJLL7
+        |F--J
+        LJ.LJ
+        "#;
+        assert_eq!(run1(&parse_pipes(input).unwrap()), 8);
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_day10p2_clean_pipes() {
+        let input = r#"
+        7-F7-
+        .FJ|7
+        SJLL7
+        |F--J
+        LJ.LJ
+        "#;
+        let pipes = parse_pipes(input.trim()).unwrap();
+        assert_eq!(pipes.count(&Pipe::None), 2);
+        let pieces = get_pieces(&pipes);
+        let pipes = clean_pipes(&pipes, &pieces);
+        assert_eq!(pipes.count(&Pipe::None), 9);
+    }
+
+    #[test]
+    fn test_day10p2_flood_fill() {
+        let input = r#"
+        .....
+        .S-7.
+        .|.|.
+        .L-J.
+        .....
+        "#;
+        let mut pipes = parse_pipes(input.trim()).unwrap();
+        graph::flood_fill(&mut pipes, (0, 0), &Pipe::Outer, |p| *p == Pipe::None);
+        assert_eq!(pipes.count(&Pipe::Outer), 16);
+    }
+
+    #[test]
+    fn test_day10p2_blow_up_1() {
+        let input = r#"
+        ..
+        .S
+        "#;
+        let mut pipes = parse_pipes(input.trim()).unwrap();
+        let pipes = blow_up(&pipes);
+        assert_eq!(pipes.count(&Pipe::Start), 9);
+        assert_eq!(pipes.count(&Pipe::None), 27);
+    }
+
+    #[test]
+    fn test_day10p2_blow_up_2() {
+        let input = r#"
+        S-7
+        |.|
+        L-J
+        "#;
+        let mut pipes = parse_pipes(input.trim()).unwrap();
+        let pipes = blow_up(&pipes);
+        assert_eq!(pipes.count(&Pipe::Start), 9);
+        assert_eq!(pipes.count(&Pipe::Horizontal), 9);
+        assert_eq!(pipes.count(&Pipe::Vertical), 9);
+        assert_eq!(pipes.count(&Pipe::TopRight), 1);
+        assert_eq!(pipes.count(&Pipe::TopLeft), 1);
+        assert_eq!(pipes.count(&Pipe::BottomLeft), 1);
+        assert_eq!(pipes.count(&Pipe::None), 51);
+    }
+
+    #[test]
+    fn test_day10p2_shrink_down_1() {
+        let input = r#"
+        ..
+        .S
+        "#;
+        let mut pipes = parse_pipes(input.trim()).unwrap();
+        let pipes = blow_up(&pipes);
+        let pipes = shrink_down(&pipes);
+        assert_eq!(pipes.count(&Pipe::Start), 1);
+        assert_eq!(pipes.count(&Pipe::None), 3);
+    }
+
+    #[test]
+    fn test_day10p2_shrink_down_2() {
+        let input = r#"
+        S-7
+        |.|
+        L-J
+        "#;
+        let mut pipes = parse_pipes(input.trim()).unwrap();
+        let pipes = blow_up(&pipes);
+        let pipes = shrink_down(&pipes);
+        assert_eq!(pipes.count(&Pipe::Start), 1);
+        assert_eq!(pipes.count(&Pipe::Horizontal), 2);
+        assert_eq!(pipes.count(&Pipe::Vertical), 2);
+        assert_eq!(pipes.count(&Pipe::TopRight), 1);
+        assert_eq!(pipes.count(&Pipe::TopLeft), 1);
+        assert_eq!(pipes.count(&Pipe::BottomLeft), 1);
+        assert_eq!(pipes.count(&Pipe::None), 1);
+    }
+
+    #[test]
+    fn test_day10p2_run_1() {
+        let input = r#"
+        .....
+        .S-7.
+        .|.|.
+        .L-J.
+        .....
+        "#;
+        assert_eq!(run2(&parse_pipes(input).unwrap()), 1);
+    }
+
+    #[test]
+    fn test_day10p2_run_2() {
+        let input = r#"
+        -L|F7
+        7S-7|
+        L|7||
+        -L-J|
+        L|-JF
+        "#;
+        assert_eq!(run2(&parse_pipes(input).unwrap()), 1);
+    }
+
+    #[test]
+    fn test_day10p2_run_3() {
+        let input = r#"
+        ..F7.
+        .FJ|.
+        SJ.L7
+        |F--J
+        LJ...
+        "#;
+        assert_eq!(run2(&parse_pipes(input).unwrap()), 1);
+    }
+
+    #[test]
+    fn test_day10p2_run_4() {
+        let input = r#"
+        7-F7-
+        .FJ|7
+        SJLL7
+        |F--J
+        LJ.LJ
+        "#;
+        assert_eq!(run2(&parse_pipes(input).unwrap()), 1);
+    }
+
+    #[test]
+    fn test_day10p2_run_5() {
+        let input = r#"
+        ...........
+        .S-------7.
+        .|F-----7|.
+        .||.....||.
+        .||.....||.
+        .|L-7.F-J|.
+        .|..|.|..|.
+        .L--J.L--J.
+        ...........
+        "#;
+        assert_eq!(run2(&parse_pipes(input).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_day10p2_run_6() {
+        let input = r#"
+        ..........
+        .S------7.
+        .|F----7|.
+        .||....||.
+        .||....||.
+        .|L-7F-J|.
+        .|..||..|.
+        .L--JL--J.
+        ..........
+        "#;
+        assert_eq!(run2(&parse_pipes(input).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_day10p2_run_7() {
+        let input = r#"
+        .F----7F7F7F7F-7....
+        .|F--7||||||||FJ....
+        .||.FJ||||||||L7....
+        FJL7L7LJLJ||LJ.L-7..
+        L--J.L7...LJS7F-7L7.
+        ....F-J..F7FJ|L7L7L7
+        ....L7.F7||L7|.L7L7|
+        .....|FJLJ|FJ|F7|.LJ
+        ....FJL-7.||.||||...
+        ....L---J.LJ.LJLJ...
+        "#;
+        assert_eq!(run2(&parse_pipes(input).unwrap()), 8);
+    }
+
+    #[test]
+    fn test_day10p2_run_8() {
+        let input = r#"
+        FF7FSF7F7F7F7F7F---7
+        L|LJ||||||||||||F--J
+        FL-7LJLJ||||||LJL-77
+        F--JF--7||LJLJ7F7FJ-
+        L---JF-JLJ.||-FJLJJ7
+        |F|F-JF---7F7-L7L|7|
+        |FFJF7L7F-JF7|JL---7
+        7-L-JL7||F7|L7F-7F7|
+        L.L7LFJ|||||FJL7||LJ
+        L7JLJL-JLJLJL--JLJ.L
+        "#;
+        assert_eq!(run2(&parse_pipes(input).unwrap()), 10);
+    }
+
+    #[test]
+    fn test_day10_parse_error() {
+        let input = "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ.x.";
+        let err = parse_pipes(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (5, 4, "x"));
+        assert!(parse_pipes(".F7\n.LJ").is_err());
+    }
+}
@@ -2,20 +2,32 @@
 
 use std::collections::BTreeSet;
 
-fn main() {
-    part2();
-}
+use crate::grid::Grid;
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
 
-fn part1() {
-    let input = include_str!("./day11.in");
-    let result = run1(input);
-    println!("{result}");
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input, 999999).into()
+    }
 }
 
-fn part2() {
-    let input = include_str!("./day11.in");
-    let result = run2(input, 999999);
-    println!("{result}");
+/// Check the image only contains galaxies and empty space.
+fn parse_input(input: &str) -> Result<String, ParseError> {
+    Grid::parse_with(input, |c| ".#".contains(c).then_some(c), "# or .")?;
+    Ok(input.trim().to_string())
 }
 
 fn run1(input: &str) -> u64 {
@@ -0,0 +1,218 @@
+#![allow(unused)]
+
+use num::{BigInt, One, Zero};
+
+use crate::parsing::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Row>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+/// A row of springs, and the sizes of the contiguous groups of damaged springs in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Row>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(parse_row)
+        .collect()
+}
+
+fn parse_row(line: &str) -> Result<Row, ParseError> {
+    let (springs, groups) = parsing::split_once(line, " ")?;
+    let springs = springs
+        .char_indices()
+        .map(|(i, c)| match c {
+            '.' => Ok(Spring::Operational),
+            '#' => Ok(Spring::Damaged),
+            '?' => Ok(Spring::Unknown),
+            _ => Err(ParseError::new(
+                &springs[i..i + c.len_utf8()],
+                "`.`, `#` or `?`",
+            )),
+        })
+        .collect::<Result<_, _>>()?;
+    let groups = groups
+        .split(',')
+        .map(parsing::number)
+        .collect::<Result<_, _>>()?;
+    Ok(Row { springs, groups })
+}
+
+fn run1(rows: &[Row]) -> BigInt {
+    rows.iter().map(arrangements).sum()
+}
+
+fn run2(rows: &[Row]) -> BigInt {
+    rows.iter().map(|row| arrangements(&row.unfold(5))).sum()
+}
+
+impl Row {
+    /// The row repeated `factor` times: the springs separated by an unknown spring each time,
+    /// and the groups simply repeated.
+    fn unfold(&self, factor: usize) -> Row {
+        let mut springs = Vec::new();
+        for i in 0..factor {
+            if i > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend(&self.springs);
+        }
+        Row {
+            springs,
+            groups: self.groups.repeat(factor),
+        }
+    }
+}
+
+/// Count the ways to fill in the unknown springs so the damaged ones form the row's groups. Long
+/// rows of unknown springs easily have more than `u128::MAX` arrangements.
+fn arrangements(row: &Row) -> BigInt {
+    let springs = &row.springs;
+    let groups = &row.groups;
+    let n = springs.len();
+
+    // Amount of operational springs before every position, to check that a group fits quickly
+    let mut operational = vec![0; n + 1];
+    for (i, spring) in springs.iter().enumerate() {
+        operational[i + 1] = operational[i] + usize::from(*spring == Spring::Operational);
+    }
+    let fits = |start: usize, len: usize| {
+        start + len <= n
+            && operational[start + len] == operational[start]
+            && springs.get(start + len) != Some(&Spring::Damaged)
+    };
+
+    // ways[i][j]: the arrangements of groups j.. over springs i.., filled in back to front. A
+    // group also takes the spring after it, so one extra position past the end is needed.
+    let mut ways = vec![vec![BigInt::zero(); groups.len() + 1]; n + 2];
+    ways[n][groups.len()] = BigInt::one();
+    ways[n + 1][groups.len()] = BigInt::one();
+    for i in (0..n).rev() {
+        for j in 0..=groups.len() {
+            let mut count = BigInt::zero();
+            if springs[i] != Spring::Damaged {
+                count += &ways[i + 1][j];
+            }
+            if springs[i] != Spring::Operational && j < groups.len() && fits(i, groups[j]) {
+                count += &ways[i + groups[j] + 1][j + 1];
+            }
+            ways[i][j] = count;
+        }
+    }
+    ways.swap_remove(0).swap_remove(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("fixtures/day12.txt");
+
+    fn row(line: &str) -> Row {
+        parse_row(line).unwrap()
+    }
+
+    #[test]
+    fn test_day12p1_example() {
+        let rows = parse_input(EXAMPLE).unwrap();
+        let counts: Vec<BigInt> = rows.iter().map(arrangements).collect();
+        assert_eq!(counts, [1, 4, 1, 1, 4, 10].map(BigInt::from));
+        assert_eq!(run1(&rows), BigInt::from(21));
+    }
+
+    #[test]
+    fn test_day12p2_example() {
+        let rows = parse_input(EXAMPLE).unwrap();
+        let counts: Vec<BigInt> = rows.iter().map(|r| arrangements(&r.unfold(5))).collect();
+        assert_eq!(counts, [1, 16384, 1, 16, 2500, 506250].map(BigInt::from));
+        assert_eq!(run2(&rows), BigInt::from(525152));
+    }
+
+    #[test]
+    fn test_day12_unfold() {
+        let unfolded = row(".# 1").unfold(3);
+        assert_eq!(unfolded, row(".#?.#?.# 1,1,1"));
+        assert_eq!(
+            arrangements(&row("???.### 1,1,3").unfold(1)),
+            BigInt::from(1)
+        );
+        assert_eq!(
+            arrangements(&row(".??..??...?##. 1,1,3").unfold(2)),
+            BigInt::from(32)
+        );
+        assert_eq!(arrangements(&row("# 1").unfold(0)), BigInt::from(1));
+    }
+
+    #[test]
+    fn test_day12_unknown_rows() {
+        // n unknown springs hold k groups totalling s damaged ones in (n - s + 1 choose k) ways
+        assert_eq!(arrangements(&row("???????????? 2,1,3")), BigInt::from(35));
+        assert_eq!(arrangements(&row("??? 1,1")), BigInt::from(1));
+        assert_eq!(arrangements(&row("?? 1,1")), BigInt::from(0));
+        assert_eq!(arrangements(&row("??? 4")), BigInt::from(0));
+        let groups = vec!["1"; 20].join(",");
+        let unknown = row(&format!("{} {groups}", "?".repeat(100)));
+        assert_eq!(
+            arrangements(&unknown),
+            BigInt::from(4694436188839116720_u64)
+        );
+    }
+
+    #[test]
+    fn test_day12_huge_counts() {
+        let unknown = |springs: usize, groups: usize| {
+            let groups = vec!["1"; groups].join(",");
+            row(&format!("{} {groups}", "?".repeat(springs)))
+        };
+        let count = |digits: &str| digits.parse::<BigInt>().unwrap();
+        // Past u64::MAX, and past u128::MAX
+        assert_eq!(
+            arrangements(&unknown(100, 30)),
+            count("95846086442150951368")
+        );
+        assert_eq!(
+            arrangements(&unknown(200, 60)),
+            count("39349707693707417946654498477149309918680")
+        );
+    }
+
+    #[test]
+    fn test_day12_parse_error() {
+        let input = "???.### 1,1,3\n.?x 1";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "x"));
+        let input = "???.### 1,a";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 11, "a"));
+    }
+}
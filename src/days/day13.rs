@@ -1,20 +1,30 @@
 #![allow(unused)]
 
-use array2d::Array2D;
+use crate::grid::Grid;
+use crate::parsing;
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 
-fn main() {
-    part2();
-}
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Vec<String>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
 
-fn part1() {
-    let input = include_str!("./day13.in");
-    let result = run1(input);
-    println!("{result}");
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
 }
 
-fn run1(input: &str) -> u32 {
+fn run1(maps: &[Vec<String>]) -> u32 {
     let mut sum = 0;
-    let maps = split(input);
     for map in maps {
         let result = find_mirror(map);
         sum += result;
@@ -22,15 +32,8 @@ fn run1(input: &str) -> u32 {
     sum
 }
 
-fn part2() {
-    let input = include_str!("./day13.in");
-    let result = run2(input);
-    println!("{result}");
-}
-
-fn run2(input: &str) -> u32 {
+fn run2(maps: &[Vec<String>]) -> u32 {
     let mut sum = 0;
-    let maps = split(input);
     for map in maps {
         let result = find_mirror_smudged(map);
         sum += result;
@@ -38,50 +41,50 @@ fn run2(input: &str) -> u32 {
     sum
 }
 
-fn split(input: &str) -> Vec<Vec<String>> {
-    let mut result = Vec::new();
-    let mut curr = Vec::new();
-    for line in input.trim().lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            result.push(curr.clone());
-            curr.clear();
-        } else {
-            curr.push(line.to_string());
+fn parse_input(input: &str) -> Result<Vec<Vec<String>>, ParseError> {
+    for pattern in parsing::paragraphs(input) {
+        for line in pattern.iter() {
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| !".#".contains(*c)) {
+                return Err(ParseError::new(&line[i..i + c.len_utf8()], "# or ."));
+            }
+            if line.len() != pattern[0].len() {
+                return Err(ParseError::new(
+                    line,
+                    format!("a line of {} tiles", pattern[0].len()),
+                ));
+            }
         }
     }
-    if !curr.is_empty() {
-        result.push(curr.clone());
-    }
-    result
+    Ok(split(input))
 }
 
-fn find_mirror(input: Vec<String>) -> u32 {
-    let mut result = 100 * find_horizontal(&input);
+fn split(input: &str) -> Vec<Vec<String>> {
+    parsing::paragraphs(input)
+        .into_iter()
+        .map(|lines| lines.into_iter().map(String::from).collect())
+        .collect()
+}
+
+fn find_mirror(input: &[String]) -> u32 {
+    let mut result = 100 * find_horizontal(input);
     if result == 0 {
-        result = find_vertical(&input);
+        result = find_vertical(input);
     }
     result
 }
 
-fn find_mirror_smudged(input: Vec<String>) -> u32 {
-    let mut result = 100 * find_horizontal_smudged(&input);
+fn find_mirror_smudged(input: &[String]) -> u32 {
+    let mut result = 100 * find_horizontal_smudged(input);
     if result == 0 {
-        result = find_vertical_smudged(&input);
+        result = find_vertical_smudged(input);
     }
     result
 }
 
 fn find_vertical(input: &[String]) -> u32 {
-    let matrix = Array2D::from_rows(
-        &input
-            .iter()
-            .map(|s| s.chars().collect::<Vec<char>>())
-            .collect::<Vec<Vec<char>>>(),
-    )
-    .unwrap();
+    let matrix = Grid::<char>::parse(&input.join("\n")).expect("Patterns are made of characters");
     let cols = matrix
-        .columns_iter()
+        .columns()
         .map(|c| c.collect::<String>())
         .collect::<Vec<String>>();
     find_horizontal(&cols)
@@ -97,15 +100,9 @@ fn find_horizontal(input: &[String]) -> u32 {
 }
 
 fn find_vertical_smudged(input: &[String]) -> u32 {
-    let matrix = Array2D::from_rows(
-        &input
-            .iter()
-            .map(|s| s.chars().collect::<Vec<char>>())
-            .collect::<Vec<Vec<char>>>(),
-    )
-    .unwrap();
+    let matrix = Grid::<char>::parse(&input.join("\n")).expect("Patterns are made of characters");
     let cols = matrix
-        .columns_iter()
+        .columns()
         .map(|c| c.collect::<String>())
         .collect::<Vec<String>>();
     find_horizontal_smudged(&cols)
@@ -230,7 +227,7 @@ mod tests {
             ..##..###
             #....#..#
         "#;
-        assert_eq!(run1(input), 405);
+        assert_eq!(run1(&split(input)), 405);
     }
 
     #[test]
@@ -284,6 +281,13 @@ mod tests {
             ..##..###
             #....#..#
         "#;
-        assert_eq!(run2(input), 400);
+        assert_eq!(run2(&split(input)), 400);
+    }
+
+    #[test]
+    fn test_day13_parse_error() {
+        let input = "#.##\n..#.\n\n#..#\n#.#";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.expected.as_str()), (5, "a line of 4 tiles"));
     }
 }
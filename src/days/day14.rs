@@ -1,88 +1,52 @@
 #![allow(unused)]
 
-use std::{collections::HashMap, fmt::Display};
+use std::fmt::Display;
 
-use array2d::Array2D;
+use crate::cycle::nth_state;
+use crate::grid::Grid;
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 
-fn main() {
-    part2();
-}
-
-fn part1() {
-    let input = include_str!("./day14.in");
-    let result = run1(input);
-    println!("{result}");
-}
+pub struct Day14;
 
-fn run1(input: &str) -> usize {
-    let map = build_map(input);
-    let map = tilt_map_north(&map);
-    calculate_total_load(&map)
-}
+impl Solution for Day14 {
+    type Input = Map;
 
-fn part2() {
-    let input = include_str!("./day14.in");
-    let result = run2(input);
-    println!("{result}");
-}
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        build_map(input)
+    }
 
-fn run2(input: &str) -> usize {
-    let mut map = build_map(input);
-    let mut cache = HashMap::new();
-    let mut maps = HashMap::new();
-    let mut period = 0;
-    let mut start = 0;
-    let mut i = 0;
-    loop {
-        i += 1;
-        let new_map = cycle(&map);
-        if let Some(idx) = cache.get(&new_map) {
-            period = i - idx;
-            start = *idx;
-            break;
-        } else {
-            cache.insert(new_map.clone(), i);
-            maps.insert(i, new_map.clone());
-        }
-        map = new_map;
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
     }
-    let needed = start + (1_000_000_000 - start) % period;
-    if let Some(map) = maps.get(&needed) {
-        calculate_total_load(map)
-    } else {
-        0
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-struct Map(Array2D<char>);
+fn run1(map: &Map) -> usize {
+    let map = tilt_map_north(map);
+    calculate_total_load(&map)
+}
 
-impl std::hash::Hash for Map {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.0
-            .rows_iter()
-            .for_each(|row| row.for_each(|c| c.hash(state)));
-    }
+fn run2(map: &Map) -> usize {
+    let map = nth_state(map.clone(), cycle, 1_000_000_000);
+    calculate_total_load(&map)
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Map(Grid<char>);
+
 impl Display for Map {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut result = String::new();
-        self.0.rows_iter().for_each(|row| {
-            row.for_each(|c| result.push(c.to_owned()));
-            result.push('\n');
-        });
-        write!(f, "{}", result)
+        write!(f, "{}", self.0)
     }
 }
 
-fn build_map(input: &str) -> Map {
-    let tiles: Vec<Vec<char>> = input
-        .trim()
-        .lines()
-        .map(|l| l.trim().chars().collect())
-        .collect();
-    Map(Array2D::from_rows(&tiles).unwrap())
+fn build_map(input: &str) -> Result<Map, ParseError> {
+    let rocks = Grid::parse_with(input, |c| "O#.".contains(c).then_some(c), "O, # or .")?;
+    Ok(Map(rocks))
 }
 
 fn cycle(map: &Map) -> Map {
@@ -94,7 +58,7 @@ fn cycle(map: &Map) -> Map {
 
 fn tilt_map_north(map: &Map) -> Map {
     let mut columns: Vec<Vec<char>> = Vec::new();
-    map.0.columns_iter().for_each(|col| {
+    map.0.columns().for_each(|col| {
         let mut curr: Vec<char> = Vec::new();
         col.enumerate().for_each(|(idx, c)| {
             match c {
@@ -112,19 +76,19 @@ fn tilt_map_north(map: &Map) -> Map {
                 _ => panic!("Unhandled char: {c}"),
             };
         });
-        for i in 0..(map.0.column_len() - curr.len()) {
+        for i in 0..(map.0.height() - curr.len()) {
             curr.push('.');
         }
         columns.push(curr);
     });
-    Map(Array2D::from_columns(&columns).unwrap())
+    Map(Grid::from_rows(columns).unwrap().transpose())
 }
 
 fn tilt_map_west(map: &Map) -> Map {
     let mut rows: Vec<Vec<char>> = Vec::new();
-    map.0.rows_iter().for_each(|row| {
+    map.0.rows().for_each(|row| {
         let mut curr: Vec<char> = Vec::new();
-        row.enumerate().for_each(|(idx, c)| {
+        row.iter().enumerate().for_each(|(idx, c)| {
             match c {
                 '.' => (),
                 '#' => {
@@ -140,17 +104,17 @@ fn tilt_map_west(map: &Map) -> Map {
                 _ => panic!("Unhandled char: {c}"),
             };
         });
-        for i in 0..(map.0.row_len() - curr.len()) {
+        for i in 0..(map.0.width() - curr.len()) {
             curr.push('.');
         }
         rows.push(curr);
     });
-    Map(Array2D::from_rows(&rows).unwrap())
+    Map(Grid::from_rows(rows).unwrap())
 }
 
 fn tilt_map_south(map: &Map) -> Map {
     let mut columns: Vec<Vec<char>> = Vec::new();
-    map.0.columns_iter().for_each(|col| {
+    map.0.columns().for_each(|col| {
         let mut curr: Vec<char> = Vec::new();
         col.rev().enumerate().for_each(|(idx, c)| {
             match c {
@@ -168,20 +132,20 @@ fn tilt_map_south(map: &Map) -> Map {
                 _ => panic!("Unhandled char: {c}"),
             };
         });
-        for i in 0..(map.0.column_len() - curr.len()) {
+        for i in 0..(map.0.height() - curr.len()) {
             curr.push('.');
         }
         curr.reverse();
         columns.push(curr);
     });
-    Map(Array2D::from_columns(&columns).unwrap())
+    Map(Grid::from_rows(columns).unwrap().transpose())
 }
 
 fn tilt_map_east(map: &Map) -> Map {
     let mut rows: Vec<Vec<char>> = Vec::new();
-    map.0.rows_iter().for_each(|row| {
+    map.0.rows().for_each(|row| {
         let mut curr: Vec<char> = Vec::new();
-        row.rev().enumerate().for_each(|(idx, c)| {
+        row.iter().rev().enumerate().for_each(|(idx, c)| {
             match c {
                 '.' => (),
                 '#' => {
@@ -197,18 +161,18 @@ fn tilt_map_east(map: &Map) -> Map {
                 _ => panic!("Unhandled char: {c}"),
             };
         });
-        for i in 0..(map.0.row_len() - curr.len()) {
+        for i in 0..(map.0.width() - curr.len()) {
             curr.push('.');
         }
         curr.reverse();
         rows.push(curr);
     });
-    Map(Array2D::from_rows(&rows).unwrap())
+    Map(Grid::from_rows(rows).unwrap())
 }
 
 fn calculate_total_load(map: &Map) -> usize {
     map.0
-        .columns_iter()
+        .columns()
         .map(|col| {
             col.rev()
                 .enumerate()
@@ -235,7 +199,7 @@ mod tests {
             #....###..
             #OO..#....
         "#;
-        assert_eq!(run1(input), 136);
+        assert_eq!(run1(&build_map(input).unwrap()), 136);
     }
 
     #[test]
@@ -252,7 +216,7 @@ mod tests {
             ###..###..
             #OO..#....
         "#;
-        let map = build_map(input);
+        let map = build_map(input).unwrap();
         let map2 = tilt_map_north(&map);
         assert_eq!(map, map2);
     }
@@ -271,9 +235,9 @@ mod tests {
             ..O.O.....
             ..........
         "#;
-        let map = build_map(input1);
+        let map = build_map(input1).unwrap();
         let map = tilt_map_north(&map);
-        let map2 = build_map(input2);
+        let map2 = build_map(input2).unwrap();
         assert_eq!(map, map2);
     }
 
@@ -291,9 +255,9 @@ mod tests {
             .O....O.O.
             OO.OO.O.#.
         "#;
-        let map = build_map(input1);
+        let map = build_map(input1).unwrap();
         let map = tilt_map_south(&map);
-        let map2 = build_map(input2);
+        let map2 = build_map(input2).unwrap();
         assert_eq!(map, map2);
     }
 
@@ -311,9 +275,9 @@ mod tests {
             OOOO......
             O.......#.
         "#;
-        let map = build_map(input1);
+        let map = build_map(input1).unwrap();
         let map = tilt_map_west(&map);
-        let map2 = build_map(input2);
+        let map2 = build_map(input2).unwrap();
         assert_eq!(map, map2);
     }
 
@@ -331,9 +295,9 @@ mod tests {
             .......OOO
             .......O#.
         "#;
-        let map = build_map(input1);
+        let map = build_map(input1).unwrap();
         let map = tilt_map_east(&map);
-        let map2 = build_map(input2);
+        let map2 = build_map(input2).unwrap();
         assert_eq!(map, map2);
     }
 
@@ -387,13 +351,13 @@ mod tests {
             #...O###.O
             #.OOO#...O
         "#;
-        let input = build_map(input1);
+        let input = build_map(input1).unwrap();
         let map2 = cycle(&input);
-        assert_eq!(map2, build_map(input2));
+        assert_eq!(map2, build_map(input2).unwrap());
         let map3 = cycle(&map2);
-        assert_eq!(map3, build_map(input3));
+        assert_eq!(map3, build_map(input3).unwrap());
         let map4 = cycle(&map3);
-        assert_eq!(map4, build_map(input4));
+        assert_eq!(map4, build_map(input4).unwrap());
     }
 
     #[test]
@@ -410,6 +374,6 @@ mod tests {
             #....###..
             #OO..#....
         "#;
-        assert_eq!(run2(input), 64);
+        assert_eq!(run2(&build_map(input).unwrap()), 64);
     }
 }
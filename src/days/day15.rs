@@ -1,30 +1,57 @@
 #![allow(unused)]
 
-fn main() {
-    part2();
-}
+use crate::parsing::{self, ParseError};
+use crate::solution::{Answer, Solution};
 
-type Map = [Vec<(String, u32)>; 256];
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
 
-fn part1() {
-    let input = include_str!("./day15.in");
-    let result = run1(input);
-    println!("{result}");
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
 }
 
-fn part2() {
-    let input = include_str!("./day15.in");
-    let result = run2(input);
-    println!("{result}");
+type Map = [Vec<(String, u32)>; 256];
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    let input = input.trim();
+    if let Some(newline) = input.find('\n') {
+        return Err(ParseError::new(&input[newline..], "a single line of steps"));
+    }
+    input
+        .split(',')
+        .map(|step| {
+            let operation = step.find(['=', '-']).unwrap_or(step.len());
+            let (label, operation) = step.split_at(operation);
+            if label.is_empty() || !label.chars().all(|c| c.is_ascii_lowercase()) {
+                return Err(ParseError::new(label, "a label of lowercase letters"));
+            }
+            if let Some(focal_length) = operation.strip_prefix('=') {
+                parsing::number::<u32>(focal_length)?;
+            } else if operation != "-" {
+                return Err(ParseError::new(operation, "`=` or `-`"));
+            }
+            Ok(step.to_string())
+        })
+        .collect()
 }
 
-fn run1(input: &str) -> u32 {
-    let input = input.trim().lines().next().unwrap();
-    input.split(',').map(hash).sum()
+fn run1(steps: &[String]) -> u32 {
+    steps.iter().map(|step| hash(step)).sum()
 }
 
-fn run2(input: &str) -> u32 {
-    let map = build_map(input);
+fn run2(steps: &[String]) -> u32 {
+    let map = build_map(steps);
     calc_result(map)
 }
 
@@ -35,11 +62,10 @@ fn hash(input: &str) -> u32 {
         .fold(0, |acc, el| ((acc + el) * 17) % 256)
 }
 
-fn build_map(input: &str) -> Map {
+fn build_map(steps: &[String]) -> Map {
     const EMPTY: Vec<(String, u32)> = Vec::new();
     let mut map: Map = [EMPTY; 256];
-    let input = input.trim().lines().next().unwrap();
-    input.split(',').for_each(|c| {
+    steps.iter().for_each(|c| {
         if let [label, number] = c.split('=').collect::<Vec<&str>>()[..] {
             let hash = hash(label);
             let idx = usize::try_from(hash).unwrap();
@@ -96,7 +122,7 @@ mod tests {
     #[test]
     fn test_day15p1_run() {
         let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        assert_eq!(run1(input), 1320);
+        assert_eq!(run1(&parse_input(input).unwrap()), 1320);
     }
 
     #[test]
@@ -108,6 +134,16 @@ mod tests {
     #[test]
     fn test_day15p2_run() {
         let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        assert_eq!(run2(input), 145);
+        assert_eq!(run2(&parse_input(input).unwrap()), 145);
+    }
+
+    #[test]
+    fn test_day15_parse_error() {
+        let input = "rn=1,cm-,qp=x";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.column, err.expected.as_str()), (13, "a number"));
+        let input = "rn=1,cm+2";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.column, err.found.as_str()), (6, "cm+2"));
     }
 }
@@ -1,26 +1,32 @@
 #![allow(unused)]
 
-use std::{
-    collections::HashSet,
-    fmt::Display,
-};
+use std::{collections::HashSet, fmt::Display};
 
-use array2d::Array2D;
+use crate::grid::{FromChar, Grid, ToChar};
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 
-fn main() {
-    part2();
-}
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
 
-fn part1() {
-    let input = include_str!("./day16.in");
-    let result = run1(input);
-    println!("{result}");
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        build_map(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
 }
 
-fn run1(input: &str) -> usize {
-    let map = build_map(input);
+fn run1(map: &Map) -> usize {
     let tiles = get_energized_tiles(
-        &map,
+        map,
         State {
             coords: (-1, 0),
             dir: (1, 0),
@@ -29,17 +35,10 @@ fn run1(input: &str) -> usize {
     tiles.len()
 }
 
-fn part2() {
-    let input = include_str!("./day16.in");
-    let result = run2(input);
-    println!("{result}");
-}
-
-fn run2(input: &str) -> usize {
-    let map = build_map(input);
+fn run2(map: &Map) -> usize {
     let mut max = 0;
-    let width = map.0.row_len();
-    let height = map.0.column_len();
+    let width = map.0.width();
+    let height = map.0.height();
     let iwidth = i32::try_from(width).unwrap();
     let iheight = i32::try_from(height).unwrap();
 
@@ -47,7 +46,7 @@ fn run2(input: &str) -> usize {
         if let Ok(x) = i32::try_from(i) {
             // Top row
             let tiles = get_energized_tiles(
-                &map,
+                map,
                 State {
                     coords: (x, -1),
                     dir: (0, 1),
@@ -61,7 +60,7 @@ fn run2(input: &str) -> usize {
         if let Ok(x) = i32::try_from(i) {
             // Bottom row
             let tiles = get_energized_tiles(
-                &map,
+                map,
                 State {
                     coords: (x, iheight),
                     dir: (0, -1),
@@ -78,7 +77,7 @@ fn run2(input: &str) -> usize {
         if let Ok(y) = i32::try_from(j) {
             // Left column
             let tiles = get_energized_tiles(
-                &map,
+                map,
                 State {
                     coords: (-1, y),
                     dir: (1, 0),
@@ -92,7 +91,7 @@ fn run2(input: &str) -> usize {
         if let Ok(y) = i32::try_from(j) {
             // Right column
             let tiles = get_energized_tiles(
-                &map,
+                map,
                 State {
                     coords: (iwidth, y),
                     dir: (-1, 0),
@@ -107,14 +106,8 @@ fn run2(input: &str) -> usize {
     max
 }
 
-fn build_map(input: &str) -> Map {
-    let tiles: Vec<Vec<Tile>> = input
-        .trim()
-        .lines()
-        .map(|l| l.trim().chars().map(Tile::from).collect())
-        .collect();
-    let map: Map = Map(Array2D::from_rows(&tiles).unwrap());
-    map
+fn build_map(input: &str) -> Result<Map, ParseError> {
+    Ok(Map(Grid::parse(input)?))
 }
 
 fn get_energized_tiles(map: &Map, initial: State) -> HashSet<(i32, i32)> {
@@ -140,14 +133,8 @@ fn get_energized_tiles(map: &Map, initial: State) -> HashSet<(i32, i32)> {
 
 fn tick(map: &Map, state: &State) -> Option<TickResult> {
     let coords = (state.coords.0 + state.dir.0, state.coords.1 + state.dir.1);
-    if coords.0 < 0 || coords.1 < 0 {
-        // println!("Path out of bounds");
-        return None;
-    }
     let dir = state.dir;
-    let x = usize::try_from(coords.0).unwrap();
-    let y = usize::try_from(coords.1).unwrap();
-    let tile = map.0.get(y, x);
+    let tile = map.0.get((i64::from(coords.0), i64::from(coords.1)));
     // println!("Checking tile {coords:?}, direction: {dir:?}. Found: {tile:?}");
     match tile {
         Some(Tile::None) => Some(TickResult {
@@ -240,7 +227,7 @@ struct TickResult {
     split: Option<State>,
 }
 
-struct Map(Array2D<Tile>);
+pub struct Map(Grid<Tile>);
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 enum Tile {
@@ -251,19 +238,35 @@ enum Tile {
     SplitterH,
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '.' => Tile::None,
-            '/' => Tile::MirrorR,
-            '\\' => Tile::MirrorL,
-            '|' => Tile::SplitterV,
-            '-' => Tile::SplitterH,
-            _ => panic!("Unknown tile char: {value}"),
+            '.' => Ok(Tile::None),
+            '/' => Ok(Tile::MirrorR),
+            '\\' => Ok(Tile::MirrorL),
+            '|' => Ok(Tile::SplitterV),
+            '-' => Ok(Tile::SplitterH),
+            _ => Err(value),
         }
     }
 }
 
+impl FromChar for Tile {
+    const EXPECTED: &'static str = "a mirror, splitter or .";
+
+    fn from_char(c: char) -> Option<Self> {
+        Tile::try_from(c).ok()
+    }
+}
+
+impl ToChar for Tile {
+    fn to_char(&self) -> char {
+        self.clone().into()
+    }
+}
+
 impl From<Tile> for char {
     fn from(value: Tile) -> Self {
         match value {
@@ -286,12 +289,7 @@ impl Display for Tile {
 
 impl Display for Map {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut result = String::new();
-        self.0.rows_iter().for_each(|row| {
-            row.for_each(|t| result.push_str(&t.to_string()));
-            result.push('\n');
-        });
-        write!(f, "{}", result)
+        write!(f, "{}", self.0)
     }
 }
 
@@ -312,7 +310,7 @@ mod tests {
                        .|....-|.\
                        ..//.|....
                     "#;
-        assert_eq!(run1(input), 46);
+        assert_eq!(run1(&build_map(input).unwrap()), 46);
     }
 
     #[test]
@@ -328,7 +326,7 @@ mod tests {
 .|....-|.\
 ..//.|....
 "#;
-        let map = build_map(input);
+        let map = build_map(input).unwrap();
         assert_eq!(map.to_string(), input);
     }
 
@@ -345,6 +343,6 @@ mod tests {
                        .|....-|.\
                        ..//.|....
                     "#;
-        assert_eq!(run2(input), 51);
+        assert_eq!(run2(&build_map(input).unwrap()), 51);
     }
 }
@@ -0,0 +1,191 @@
+#![allow(unused)]
+
+use crate::geometry::{Coord, Direction};
+use crate::graph;
+use crate::grid::Grid;
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        match run1(input) {
+            Some(heat_loss) => heat_loss.into(),
+            None => "The crucible can't reach the factory".into(),
+        }
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        match run2(input) {
+            Some(heat_loss) => heat_loss.into(),
+            None => "The crucible can't reach the factory".into(),
+        }
+    }
+}
+
+fn run1(map: &Map) -> Option<usize> {
+    Crucible::NORMAL.min_heat_loss(map)
+}
+
+fn run2(map: &Map) -> Option<usize> {
+    Crucible::ULTRA.min_heat_loss(map)
+}
+
+type Map = Grid<usize>;
+
+fn parse_map(input: &str) -> Result<Map, ParseError> {
+    Grid::parse_with(input, |c| c.to_digit(10).map(|d| d as usize), "a digit")
+}
+
+/// How a crucible moves: once it starts moving in a direction it has to move at least `min_run`
+/// blocks before it can turn or stop, and it can't move more than `max_run` blocks in a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Crucible {
+    min_run: u32,
+    max_run: u32,
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+struct State {
+    position: Coord,
+    direction: Direction,
+    /// The amount of blocks moved in `direction` so far, 0 when the crucible hasn't moved yet.
+    run: u32,
+}
+
+impl Crucible {
+    const NORMAL: Crucible = Crucible {
+        min_run: 1,
+        max_run: 3,
+    };
+    const ULTRA: Crucible = Crucible {
+        min_run: 4,
+        max_run: 10,
+    };
+
+    /// The least heat lost moving the crucible from the top left to the bottom right block, or
+    /// `None` if it can't get there.
+    fn min_heat_loss(&self, map: &Map) -> Option<usize> {
+        let goal = (map.width() as i64 - 1, map.height() as i64 - 1);
+        let start = State {
+            position: (0, 0),
+            direction: Direction::Right,
+            run: 0,
+        };
+        let neighbours = |state: &State| {
+            self.moves(state)
+                .filter_map(|next| map.get(next.position).map(|&loss| (next, loss)))
+                .collect::<Vec<_>>()
+        };
+        let is_goal = |state: &State| state.position == goal && state.run >= self.min_run;
+        graph::shortest_path(start, neighbours, is_goal).map(|path| path.cost)
+    }
+
+    /// All states the crucible can move to in one block, ignoring the map's bounds.
+    fn moves<'a>(&'a self, state: &'a State) -> impl Iterator<Item = State> + 'a {
+        Direction::ALL.into_iter().filter_map(move |direction| {
+            let run = if state.run == 0 {
+                1
+            } else if direction == state.direction {
+                state.run + 1
+            } else if direction != state.direction.opposite() && state.run >= self.min_run {
+                1
+            } else {
+                return None;
+            };
+            (run <= self.max_run).then(|| State {
+                position: direction.step(state.position, 1),
+                direction,
+                run,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day17p1_run() {
+        let input = r#"
+            2413432311323
+            3215453535623
+            3255245654254
+            3446585845452
+            4546657867536
+            1438598798454
+            4457876987766
+            3637877979653
+            4654967986887
+            4564679986453
+            1224686865563
+            2546548887735
+            4322674655533
+        "#;
+        assert_eq!(run1(&parse_map(input).unwrap()), Some(102));
+    }
+
+    #[test]
+    fn test_day17p2_run_1() {
+        let input = r#"
+            2413432311323
+            3215453535623
+            3255245654254
+            3446585845452
+            4546657867536
+            1438598798454
+            4457876987766
+            3637877979653
+            4654967986887
+            4564679986453
+            1224686865563
+            2546548887735
+            4322674655533
+        "#;
+        assert_eq!(run2(&parse_map(input).unwrap()), Some(94));
+    }
+
+    #[test]
+    fn test_day17p2_run_2() {
+        let input = r#"
+            111111111111
+            999999999991
+            999999999991
+            999999999991
+            999999999991
+        "#;
+        assert_eq!(run2(&parse_map(input).unwrap()), Some(71));
+    }
+
+    #[test]
+    fn test_day17_crucibles() {
+        let input = r#"
+            111111111111
+            999999999991
+            999999999991
+            999999999991
+            999999999991
+        "#;
+        let map = parse_map(input).unwrap();
+        assert_eq!(Crucible::NORMAL.min_heat_loss(&map), Some(59));
+        assert_eq!(Crucible::ULTRA.min_heat_loss(&map), Some(71));
+        let unlimited = Crucible {
+            min_run: 1,
+            max_run: 12,
+        };
+        assert_eq!(unlimited.min_heat_loss(&map), Some(15));
+        let stuck = Crucible {
+            min_run: 13,
+            max_run: 13,
+        };
+        assert_eq!(stuck.min_heat_loss(&map), None);
+    }
+}
@@ -0,0 +1,227 @@
+#![allow(unused)]
+
+use polygonical::{point::Point, polygon::Polygon};
+
+use crate::geometry::{Coord, Direction};
+use crate::graph;
+use crate::grid::Grid;
+use crate::parsing::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = DigPlan;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(&input.plain).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(&input.colours).into()
+    }
+}
+
+fn run1(cmds: &[Cmd]) -> u64 {
+    let mut map = build_map(cmds);
+    let centre = (
+        i64::try_from(map.height() / 2).unwrap(),
+        i64::try_from(map.width() / 2).unwrap(),
+    );
+    graph::flood_fill(&mut map, centre, &false, |tile| *tile);
+    u64::try_from(map.count(&false)).unwrap()
+}
+
+fn run2(cmds: &[Cmd]) -> u64 {
+    let poly = build_polygon(cmds);
+    // Calculate the "surface" of the axis-aligned lines
+    let surface: f64 = 0.5
+        * poly
+            .sides()
+            .iter()
+            .map(|(p1, p2)| ((p2.x - p1.x) + (p2.y - p1.y)).abs())
+            .sum::<f64>();
+    let mut area = poly.area().abs(); // Abs in case of wrong girality
+
+    // All corners lie on integer coordinates, so the area is a whole number
+    (area + surface + 1.0).round() as u64 // add starting position
+}
+
+/// The dig plan read both ways: plainly for part 1, and from the colour codes for part 2.
+#[derive(Debug)]
+pub struct DigPlan {
+    plain: Vec<Cmd>,
+    colours: Vec<Cmd>,
+}
+
+fn parse_input(input: &str) -> Result<DigPlan, ParseError> {
+    Ok(DigPlan {
+        plain: parse_cmds(input, false)?,
+        colours: parse_cmds(input, true)?,
+    })
+}
+
+fn parse_cmds(input: &str, use_col: bool) -> Result<Vec<Cmd>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|line| {
+            let line = line.trim();
+            let (dir, rest) = parsing::split_once(line, " ")?;
+            let (num, col) = parsing::split_once(rest, " ")?;
+            if use_col {
+                // Remove parentheses and # from around colour value
+                let hex = parsing::prefix(col, "(#")?;
+                let hex = hex
+                    .strip_suffix(')')
+                    .ok_or_else(|| ParseError::after(col, "`)`"))?;
+                if hex.len() != 6 || !hex.is_ascii() {
+                    return Err(ParseError::new(hex, "6 hexadecimal digits"));
+                }
+                let (num, dir) = hex.split_at(5);
+                let dir = match dir {
+                    "0" => Direction::Right,
+                    "1" => Direction::Down,
+                    "2" => Direction::Left,
+                    "3" => Direction::Up,
+                    _ => return Err(ParseError::new(dir, "a direction from 0 to 3")),
+                };
+                let num = u32::from_str_radix(num, 16)
+                    .map_err(|_| ParseError::new(num, "a hexadecimal number"))?;
+                Ok(Cmd { dir, num })
+            } else {
+                let dir = match dir {
+                    "U" => Direction::Up,
+                    "D" => Direction::Down,
+                    "L" => Direction::Left,
+                    "R" => Direction::Right,
+                    d => return Err(ParseError::new(d, "a direction (U, D, L or R)")),
+                };
+                let num = parsing::number(num)?;
+                Ok(Cmd { dir, num })
+            }
+        })
+        .collect()
+}
+
+fn build_polygon(cmds: &[Cmd]) -> Polygon {
+    let dim = cmds.iter().fold([(0, 0), (0, 0), (0, 0)], |acc, cmd| {
+        let val = cmd.dir.step(acc[0], i64::from(cmd.num));
+        let min = acc[1];
+        let max = acc[2];
+        let min = (min.0.min(val.0), min.1.min(val.1));
+        let max = (max.0.max(val.0), max.1.max(val.1));
+        [val, min, max]
+    });
+    let min = dim[1];
+    let max = dim[2];
+    let dim = (1 + max.0 - min.0, 1 + max.1 - min.1);
+
+    let mut pos: Coord = (min.0.abs(), min.1.abs());
+
+    let mut points = Vec::new();
+    for cmd in cmds {
+        pos = cmd.dir.step(pos, i64::from(cmd.num));
+        points.push(Point::new(pos.0 as f64, pos.1 as f64));
+    }
+
+    Polygon::new(points)
+}
+
+fn build_map(cmds: &[Cmd]) -> Map {
+    let dim = cmds.iter().fold([(0, 0), (0, 0), (0, 0)], |acc, cmd| {
+        let val = cmd.dir.step(acc[0], i64::from(cmd.num));
+        let min = acc[1];
+        let max = acc[2];
+        let min = (min.0.min(val.0), min.1.min(val.1));
+        let max = (max.0.max(val.0), max.1.max(val.1));
+        [val, min, max]
+    });
+    let min = dim[1];
+    let max = dim[2];
+    let dim = (1 + max.0 - min.0, 1 + max.1 - min.1);
+    let cols = usize::try_from(dim.0).unwrap();
+    let rows = usize::try_from(dim.1).unwrap();
+    let mut map = Grid::filled(cols, rows, true);
+
+    let mut pos: Coord = (min.0.abs(), min.1.abs());
+    map[pos] = false;
+
+    for cmd in cmds {
+        for _ in 0..cmd.num {
+            pos = cmd.dir.step(pos, 1);
+            map[pos] = false;
+        }
+    }
+    map
+}
+
+type Map = Grid<bool>;
+
+#[derive(Debug)]
+pub struct Cmd {
+    pub dir: Direction,
+    pub num: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_day18p1_run() {
+        let input = r#"
+            R 6 (#70c710)
+            D 5 (#0dc571)
+            L 2 (#5713f0)
+            D 2 (#d2c081)
+            R 2 (#59c680)
+            D 2 (#411b91)
+            L 5 (#8ceee2)
+            U 2 (#caa173)
+            L 1 (#1b58a2)
+            U 2 (#caa171)
+            R 2 (#7807d2)
+            U 3 (#a77fa3)
+            L 2 (#015232)
+            U 2 (#7a21e3)
+        "#;
+        assert_eq!(run1(&parse_input(input).unwrap().plain), 62);
+    }
+
+    #[test]
+    pub fn test_day18p2_run() {
+        let input = r#"
+            R 6 (#70c710)
+            D 5 (#0dc571)
+            L 2 (#5713f0)
+            D 2 (#d2c081)
+            R 2 (#59c680)
+            D 2 (#411b91)
+            L 5 (#8ceee2)
+            U 2 (#caa173)
+            L 1 (#1b58a2)
+            U 2 (#caa171)
+            R 2 (#7807d2)
+            U 3 (#a77fa3)
+            L 2 (#015232)
+            U 2 (#7a21e3)
+        "#;
+        assert_eq!(run2(&parse_input(input).unwrap().colours), 952408144115);
+    }
+
+    #[test]
+    pub fn test_day18_parse_error() {
+        let input = "R 6 (#70c710)\nX 5 (#0dc571)";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "X"));
+        let input = "R 6 (#70c710)\nD 5 (#0dc575)";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 12, "5"));
+    }
+}
@@ -4,70 +4,94 @@ use std::collections::HashMap;
 
 use regex::Regex;
 
-fn main() {
-    let input = include_str!("./day19.in");
-    let result = part2(input);
-    println!("{result}");
+use crate::parsing::{self, ParseError};
+use crate::ranges::HyperRect;
+use crate::solution::{Answer, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
 
-fn part1(input: &str) -> u32 {
-    let mut input_parts = input.trim().split("\n\n");
-    let mut workflow = parse_workflow(input_parts.next().unwrap());
-    let parts = parse_parts(input_parts.next().unwrap());
+fn part1(system: &System) -> u32 {
+    let mut workflow = system.workflow.clone();
     simplify_workflow(&mut workflow);
-    parts
+    system
+        .parts
         .iter()
         .filter(|p| run_workflow(p, &workflow, "in"))
         .map(|p| p.x + p.m + p.a + p.s)
         .sum()
 }
 
-fn part2(input: &str) -> u64 {
-    let workflow = parse_workflow(input);
-    let tree = workflow_as_tree(workflow, "in");
+fn part2(system: &System) -> u64 {
+    let tree = workflow_as_tree(system.workflow.clone(), "in");
     let mut intervals = flatten_tree(tree);
     intervals.retain(|int| int.accept == Some(true));
     intervals
         .iter()
-        .map(|int| {
-            let x = int.x_max as u64 - int.x_min as u64 - 1;
-            let m = int.m_max as u64 - int.m_min as u64 - 1;
-            let a = int.a_max as u64 - int.a_min as u64 - 1;
-            let s = int.s_max as u64 - int.s_min as u64 - 1;
-            x * m * a * s
-        })
+        .map(|int| int.ratings.volume() as u64)
         .sum()
 }
 
-fn parse_workflow(input: &str) -> Workflow {
+fn parse_input(input: &str) -> Result<System, ParseError> {
+    let mut input_parts = input.trim().split("\n\n");
+    let workflow = parse_workflow(input_parts.next().unwrap_or_default())?;
+    let parts = parse_parts(input_parts.next().unwrap_or_default())?;
+    Ok(System { workflow, parts })
+}
+
+fn parse_workflow(input: &str) -> Result<Workflow, ParseError> {
     let mut workflow = HashMap::new();
+    // For every workflow, the names of the workflows its rules send parts to
+    let mut references: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut names = Vec::new();
+    // (field)(<|>)(value):(result)
+    let pattern = Regex::new(r"^(\w)(<|>)(\d+):(\w+)$").unwrap();
     for line in input.trim().lines() {
         let line = line.trim();
         if line.is_empty() {
-            println!("Workflow parsed");
             break;
         }
-        let mut it = line.split('{');
-        let name = it.next().expect("Invalid rule line: {line}");
-        let rule_line = it.next().unwrap().split('}').next().unwrap();
+        let (name, rule_line) = parsing::split_once(line, "{")?;
+        let rule_line = rule_line
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::after(line, "`}`"))?;
         let mut rules = Vec::new();
-        // println!("==================");
-        // println!("Parsing rule '{name}'");
-        for r in rule_line.split(',') {
-            // println!("Parsing: {r}");
+        names.push(name);
+        let targets = references.entry(name).or_default();
+        let count = rule_line.split(',').count();
+        for (i, r) in rule_line.split(',').enumerate() {
             let mut result = parse_rule_result(r);
-            let rule = if r.contains(':') {
-                // (field)(<|>)(value):(result)
-                let pattern = Regex::new(r"(\w)(<|>)(\d+):(\w*)").unwrap();
-                let (_, [field, op, value, res]) = pattern.captures(r).unwrap().extract();
+            let mut target = r;
+            // Every rule but the last has a condition, so a part always gets somewhere
+            let rule = if i + 1 < count {
+                let Some((_, [field, op, value, res])) = pattern.captures(r).map(|c| c.extract())
+                else {
+                    return Err(ParseError::new(r, "a rule like `a<2006:qkq`"));
+                };
                 result = parse_rule_result(res);
-                let value = value.parse::<u32>().expect("Invalid value: {value}");
+                target = res;
+                let value = parsing::number::<u32>(value)?;
                 let field = match field {
                     "x" => Field::X,
                     "m" => Field::M,
                     "a" => Field::A,
                     "s" => Field::S,
-                    _ => panic!("Field not supported: {field}"),
+                    _ => return Err(ParseError::new(field, "x, m, a or s")),
                 };
                 match op {
                     ">" => Rule::GT {
@@ -75,22 +99,67 @@ fn parse_workflow(input: &str) -> Workflow {
                         value,
                         then: result,
                     },
-                    "<" => Rule::LT {
+                    _ => Rule::LT {
                         field,
                         value,
                         then: result,
                     },
-                    _ => panic!("Operator not supported: {op}"),
                 }
+            } else if r.contains(':') {
+                return Err(ParseError::new(r, "a last rule without a condition"));
             } else {
                 Rule::Result(result)
             };
+            if !matches!(target, "A" | "R") {
+                targets.push(target);
+            }
             rules.push(rule);
-            // println!("\t {rule:?}")
         }
         workflow.insert(name.to_string(), rules);
     }
-    workflow
+
+    if !workflow.contains_key("in") {
+        return Err(ParseError::after(input.trim_end(), "a workflow named `in`"));
+    }
+    // Check in the order of the input, so the first problem is the one reported
+    for target in names.iter().flat_map(|name| &references[name]) {
+        if !workflow.contains_key(*target) {
+            return Err(ParseError::new(target, "a workflow that is defined"));
+        }
+    }
+    let mut done = HashMap::new();
+    for name in names {
+        if let Some(target) = find_loop(name, &references, &mut done) {
+            return Err(ParseError::new(
+                target,
+                "a workflow that doesn't lead back to itself",
+            ));
+        }
+    }
+    Ok(workflow)
+}
+
+/// Follow the references from workflow `name`, returning a reference that leads back to a
+/// workflow already being followed, if any. `done` tracks the workflows being followed (false)
+/// and the ones known not to loop (true).
+fn find_loop<'a>(
+    name: &'a str,
+    references: &HashMap<&'a str, Vec<&'a str>>,
+    done: &mut HashMap<&'a str, bool>,
+) -> Option<&'a str> {
+    match done.get(name) {
+        Some(true) => return None,
+        Some(false) => return Some(name),
+        None => (),
+    }
+    done.insert(name, false);
+    for &target in references.get(name).into_iter().flatten() {
+        if let Some(target) = find_loop(target, references, done) {
+            return Some(target);
+        }
+    }
+    done.insert(name, true);
+    None
 }
 
 fn parse_rule_result(r: &str) -> RuleResult {
@@ -106,17 +175,15 @@ fn simplify_workflow(workflow: &mut Workflow) {
     loop {
         for (name, rules) in workflow.iter() {
             let first_result = get_result(rules.first().unwrap());
-            if rules.iter().all(|rule| *first_result == *get_result(rule)) {
-                // println!("Should simplify: {name} => {rules:?}");
+            // The start has to stay, as that is where every part is sent first
+            if name != "in" && rules.iter().all(|rule| *first_result == *get_result(rule)) {
                 todo.push((name.clone(), first_result.clone()));
             }
         }
         if todo.is_empty() {
-            println!("Simplification done");
             return;
         }
         for (to_remove, replace_result) in todo.drain(0..) {
-            // println!("Replacing rule {to_remove} with {replace_result:?}");
             workflow.remove(&to_remove);
             for (_, rules) in workflow.iter_mut() {
                 for rule in rules.iter_mut() {
@@ -142,23 +209,27 @@ fn get_result(rule: &Rule) -> &RuleResult {
     }
 }
 
-fn parse_parts(input: &str) -> Vec<Part> {
+fn parse_parts(input: &str) -> Result<Vec<Part>, ParseError> {
     let mut parts = Vec::new();
+    let pattern = Regex::new(r"^\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}$").unwrap();
     for line in input.trim().lines() {
         let line = line.trim();
         if line.is_empty() {
-            println!("Parts parsed");
             break;
         }
-        let pattern = Regex::new(r"x=(\d+),m=(\d+),a=(\d+),s=(\d+)").unwrap();
-        let (_, [x, m, a, s]) = pattern.captures(line).unwrap().extract();
-        let x = x.parse::<u32>().expect("Invalid x: {x}");
-        let m = m.parse::<u32>().expect("Invalid m: {m}");
-        let a = a.parse::<u32>().expect("Invalid a: {a}");
-        let s = s.parse::<u32>().expect("Invalid s: {s}");
+        let Some((_, [x, m, a, s])) = pattern.captures(line).map(|c| c.extract()) else {
+            return Err(ParseError::new(
+                line,
+                "a part like `{x=787,m=2655,a=1222,s=2876}`",
+            ));
+        };
+        let x = parsing::number::<u32>(x)?;
+        let m = parsing::number::<u32>(m)?;
+        let a = parsing::number::<u32>(a)?;
+        let s = parsing::number::<u32>(s)?;
         parts.push(Part { x, m, a, s });
     }
-    parts
+    Ok(parts)
 }
 
 fn run_workflow(part: &Part, workflow: &Workflow, start: impl ToString) -> bool {
@@ -220,7 +291,6 @@ fn workflow_as_tree(mut workflow: Workflow, start: impl ToString) -> Box<TreeNod
             if !has_gotos {
                 let tree = build_tree(rules);
                 if name == &start {
-                    println!("Workflow converted to tree");
                     return tree;
                 }
                 todo.push((name.to_owned(), tree));
@@ -230,7 +300,6 @@ fn workflow_as_tree(mut workflow: Workflow, start: impl ToString) -> Box<TreeNod
             panic!("Something went wrong during parsing");
         }
         for (to_remove, tree) in todo.drain(0..) {
-            println!("Replacing rule \"{to_remove}\"...");
             workflow.remove(&to_remove);
             for (_, rules) in workflow.iter_mut() {
                 for rule in rules.iter_mut() {
@@ -339,12 +408,7 @@ fn flatten_tree(tree: Box<TreeNode>) -> Vec<Interval> {
     let mut intervals = Vec::new();
     evaluate_tree_node(tree, &interval, &mut intervals);
     // Remove all "impossible" intervals
-    intervals.retain(|int| {
-        int.x_min < int.x_max
-            && int.m_min < int.m_max
-            && int.a_min < int.a_max
-            && int.s_min < int.s_max
-    });
+    intervals.retain(|int| !int.ratings.is_empty());
     intervals
 }
 
@@ -366,39 +430,34 @@ fn evaluate_tree_node(node: Box<TreeNode>, interval: &Interval, intervals: &mut
 }
 
 fn split_interval(interval: Interval, field: Field, lt: bool, value: u32) -> [Interval; 2] {
-    // println!("-------------------");
-    // println!("Expr: {:?} {} {}", field, if lt { '<' } else { '>' }, value);
-    // println!("Input: {interval:?}");
-    let mut interval1 = interval;
-    let mut interval2 = interval;
-    let delta: i32 = if lt {
-        -1
+    let axis = field as usize;
+    let (below, above) = if lt {
+        interval.ratings.split_at(axis, value)
     } else {
-        1
+        // No rating reaches u32::MAX, so saturating still splits at the right place
+        interval.ratings.split_at(axis, value.saturating_add(1))
     };
-    update_interval(&mut interval1, field, lt, value);
-    update_interval(&mut interval2, field, !lt, ((value as i32) + delta) as u32);
-    // println!("First: {interval1:?}");
-    // println!("Second: {interval2:?}");
-    // println!("-------------------");
-    [interval1, interval2]
-}
-
-fn update_interval(interval: &mut Interval, field: Field, lt: bool, value: u32) {
-    match (field, lt) {
-        (Field::X, true) => interval.x_max = interval.x_max.min(value),
-        (Field::X, false) => interval.x_min = interval.x_min.max(value),
-        (Field::M, true) => interval.m_max = interval.m_max.min(value),
-        (Field::M, false) => interval.m_min = interval.m_min.max(value),
-        (Field::A, true) => interval.a_max = interval.a_max.min(value),
-        (Field::A, false) => interval.a_min = interval.a_min.max(value),
-        (Field::S, true) => interval.s_max = interval.s_max.min(value),
-        (Field::S, false) => interval.s_min = interval.s_min.max(value),
-    }
+    let (ok, not_ok) = if lt { (below, above) } else { (above, below) };
+    [
+        Interval {
+            ratings: ok,
+            ..interval
+        },
+        Interval {
+            ratings: not_ok,
+            ..interval
+        },
+    ]
 }
 
 type Workflow = HashMap<String, Vec<Rule>>;
 
+#[derive(Debug)]
+pub struct System {
+    workflow: Workflow,
+    parts: Vec<Part>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Field {
     X,
@@ -455,31 +514,17 @@ struct ExprNode {
     not_ok: Box<TreeNode>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct Interval {
-    x_min: u32,
-    x_max: u32,
-    m_min: u32,
-    m_max: u32,
-    a_min: u32,
-    a_max: u32,
-    s_min: u32,
-    s_max: u32,
+    /// The x, m, a and s ratings in the interval.
+    ratings: HyperRect<u32, 4>,
     accept: Option<bool>,
 }
 
 impl Default for Interval {
     fn default() -> Self {
-        // Off by one wrt puzzle because I'm working entirely exclusively
         Self {
-            x_min: 0,
-            x_max: 4001,
-            m_min: 0,
-            m_max: 4001,
-            a_min: 0,
-            a_max: 4001,
-            s_min: 0,
-            s_max: 4001,
+            ratings: HyperRect::new([1..4001, 1..4001, 1..4001, 1..4001]),
             accept: None,
         }
     }
@@ -510,7 +555,7 @@ mod tests {
             {x=2461,m=1339,a=466,s=291}
             {x=2127,m=1623,a=2188,s=1013}
         "#;
-        assert_eq!(part1(input), 19114);
+        assert_eq!(part1(&parse_input(input).unwrap()), 19114);
     }
 
     #[test]
@@ -530,7 +575,7 @@ mod tests {
 
             ignoreme
         "#;
-        let workflow = parse_workflow(input);
+        let workflow = parse_workflow(input).unwrap();
         assert_eq!(workflow.len(), 11);
     }
 
@@ -552,7 +597,7 @@ mod tests {
             ignoreme
         "#;
         // will be simplified: gd, lnx, qs
-        let mut workflow = parse_workflow(input);
+        let mut workflow = parse_workflow(input).unwrap();
         simplify_workflow(&mut workflow);
         assert_eq!(workflow.len(), 8);
     }
@@ -567,7 +612,7 @@ mod tests {
             {x=2127,m=1623,a=2188,s=1013}
         "#;
         assert_eq!(
-            parse_parts(input),
+            parse_parts(input).unwrap(),
             vec![
                 Part {
                     x: 787,
@@ -624,7 +669,7 @@ mod tests {
             {x=2461,m=1339,a=466,s=291}
             {x=2127,m=1623,a=2188,s=1013}
         "#;
-        assert_eq!(part2(input), 167409079868000);
+        assert_eq!(part2(&parse_input(input).unwrap()), 167409079868000);
     }
 
     #[test]
@@ -634,9 +679,55 @@ mod tests {
             px{a<1342:R,A}
             qqz{a>3333:R,A}
         "#;
-        let workflow = parse_workflow(input);
+        let workflow = parse_workflow(input).unwrap();
         let tree = workflow_as_tree(workflow, "in");
         let intervals = flatten_tree(tree);
         assert_eq!(intervals.len(), 3);
     }
+
+    #[test]
+    pub fn test_day19_parse_error() {
+        let input = "in{a<2006:pv,m>2090:A,R}\npv{a>1716:R,A}\n\n{x=787,m=2655,a=1222,s=2876}";
+        assert!(parse_input(input).is_ok());
+        let input = "in{a<2006:pv,m>2090:A,R}\npv{a>1716:R,A\n\n{x=787,m=2655,a=1222,s=2876}";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 14, "`}`")
+        );
+        let input = "in{a<2006:pv,y>2090:A,R}\n\n{x=787,m=2655,a=1222,s=2876}";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 14, "y"));
+        let input = "in{a<2006:A,m>2090:A,R}\n\n{x=787,m=2655,a=1222}";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[test]
+    pub fn test_day19_invalid_workflows() {
+        let error = |input: &str| {
+            let err = parse_workflow(input).unwrap_err().locate(input);
+            (err.line, err.column, err.found)
+        };
+        // A missing start, a missing workflow and conditions in the wrong place
+        assert_eq!(error("px{a<5:A,R}"), (1, 12, String::new()));
+        assert_eq!(error("in{a<5:px,R}"), (1, 8, "px".to_string()));
+        assert_eq!(error("in{A,a<5:R}"), (1, 4, "A".to_string()));
+        assert_eq!(error("in{a<5:R}"), (1, 4, "a<5:R".to_string()));
+        assert_eq!(error("in{}"), (1, 4, String::new()));
+        // Workflows that loop back on themselves
+        assert_eq!(error("in{a<5:in,R}"), (1, 8, "in".to_string()));
+        assert_eq!(
+            error("in{px}\npx{a<5:R,qs}\nqs{x>5:px,A}"),
+            (3, 8, "px".to_string())
+        );
+    }
+
+    #[test]
+    pub fn test_day19_large_values() {
+        let input = "in{x>4294967295:R,a<1:R,A}\n\n{x=1,m=1,a=1,s=1}";
+        let system = parse_input(input).unwrap();
+        assert_eq!(part1(&system), 4);
+        assert_eq!(part2(&system), 4000_u64.pow(4));
+    }
 }
@@ -0,0 +1,395 @@
+#![allow(unused)]
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::cycle::Cycle;
+use crate::math::lcm_all;
+use crate::parsing::{self, ParseError};
+use crate::solution::{Answer, Solution};
+use crate::symbols::Interner;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Machine;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_modules(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        match part2(input) {
+            Some(presses) => presses.into(),
+            None => "rx isn't fed by a conjunction whose inputs all send it high pulses".into(),
+        }
+    }
+}
+
+/// The amount of times the button is pushed in part 1.
+const PRESSES: usize = 1000;
+
+/// How long part 2 waits for a module to send its pulse before giving up. The modules feeding
+/// `rx` are driven by 12 bit counters, so they do well within this.
+const MAX_PRESSES: usize = 1 << 16;
+
+fn part1(machine: &Machine) -> usize {
+    // The low and high pulses sent by each press. Most machines only repeat their state after
+    // far more presses than that, but if one repeats earlier, the counts repeat with it.
+    let mut seen: HashMap<State, usize> = HashMap::new();
+    let mut counts: Vec<(usize, usize)> = Vec::new();
+    let mut state = machine.initial_state();
+    let mut cycle = None;
+    while counts.len() < PRESSES {
+        if let Some(&start) = seen.get(&state) {
+            let period = counts.len() - start;
+            cycle = Some(Cycle { start, period });
+            break;
+        }
+        seen.insert(state.clone(), counts.len());
+        let mut pulses = Vec::new();
+        tick(machine, &mut state, &mut pulses);
+        let high = pulses.iter().filter(|&&p| p).count();
+        counts.push((pulses.len() - high, high));
+    }
+
+    let (low, high) = (0..PRESSES)
+        .map(|n| counts[cycle.map_or(n, |cycle| cycle.reduce(n))])
+        .fold((0, 0), |(low, high), (l, h)| (low + l, high + h));
+    low * high
+}
+
+/// The presses until `rx` gets a low pulse. `rx` is fed by a single conjunction, so that is when
+/// the modules feeding the conjunction all send it a high pulse in the same press, and each of
+/// them does so at a fixed interval.
+fn part2(machine: &Machine) -> Option<usize> {
+    let rx = machine.names.get("rx")?;
+    let [conjunction] = machine.inputs_of(rx)[..] else {
+        return None;
+    };
+    if machine.modules[conjunction as usize].kind != Kind::Conjunction {
+        return None;
+    }
+    let mut cycles = Vec::new();
+    for module in machine.inputs_of(conjunction) {
+        cycles.push(get_cycles(machine, module, true)?);
+    }
+    Some(lcm_all(cycles))
+}
+
+/// The presses until `check_for` first sends a `check_signal` pulse, `None` if it doesn't within
+/// [`MAX_PRESSES`].
+fn get_cycles(machine: &Machine, check_for: u32, check_signal: bool) -> Option<usize> {
+    let mut state = machine.initial_state();
+    let mut pulses = Vec::new();
+    for cycle in 1..=MAX_PRESSES {
+        if tick_until(machine, &mut state, &mut pulses, check_for, check_signal) {
+            return Some(cycle);
+        }
+        pulses.clear();
+    }
+    None
+}
+
+fn parse_modules(input: &str) -> Result<Machine, ParseError> {
+    let mut names = Interner::new();
+    let broadcaster = names.intern("broadcaster");
+    let mut definitions = Vec::new();
+    for line in input
+        .trim()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+    {
+        let (input, output) = parsing::split_once(line, " -> ")?;
+        let (kind, name) = match input {
+            s if s.starts_with('%') => (Kind::FlipFlop, &s[1..]),
+            s if s.starts_with('&') => (Kind::Conjunction, &s[1..]),
+            "broadcaster" => (Kind::Broadcaster, input),
+            s => {
+                return Err(ParseError::new(
+                    s,
+                    "broadcaster, or a module starting with % or &",
+                ))
+            }
+        };
+        let id = names.intern(name);
+        let outputs: Vec<u32> = output.split(", ").map(|s| names.intern(s)).collect();
+        definitions.push((id, kind, outputs));
+    }
+
+    let mut modules = vec![
+        Module {
+            kind: Kind::Sink,
+            outputs: Vec::new(),
+            memory: 0,
+            inputs: 0,
+        };
+        names.len()
+    ];
+    for (id, kind, outputs) in definitions {
+        let module = &mut modules[id as usize];
+        module.kind = kind;
+        module.outputs = outputs.into_iter().map(|to| Wire { to, slot: 0 }).collect();
+    }
+    // Number the inputs of every module, and give each its bits of memory
+    let mut inputs = vec![0; modules.len()];
+    for wire in modules.iter_mut().flat_map(|module| &mut module.outputs) {
+        wire.slot = inputs[wire.to as usize];
+        inputs[wire.to as usize] += 1;
+    }
+    let mut size = 0;
+    for (module, count) in modules.iter_mut().zip(inputs) {
+        module.memory = size;
+        module.inputs = count;
+        size += match module.kind {
+            Kind::FlipFlop => 1,
+            Kind::Conjunction => count,
+            Kind::Broadcaster | Kind::Sink => 0,
+        };
+    }
+
+    Ok(Machine {
+        names,
+        modules,
+        broadcaster,
+        size,
+    })
+}
+
+fn tick(machine: &Machine, state: &mut State, pulses: &mut Vec<bool>) {
+    send_pulses(machine, state, pulses, |_, _| false);
+}
+
+fn tick_until(
+    machine: &Machine,
+    state: &mut State,
+    pulses: &mut Vec<bool>,
+    check_for: u32,
+    check_signal: bool,
+) -> bool {
+    send_pulses(machine, state, pulses, |from, signal| {
+        from == Some(check_for) && signal == check_signal
+    })
+}
+
+/// Press the button and handle the pulses until there are none left, or until `stop` returns
+/// true for a pulse, given the module that sent it (`None` for the button) and its signal.
+/// Returns whether it stopped early.
+fn send_pulses(
+    machine: &Machine,
+    state: &mut State,
+    pulses: &mut Vec<bool>,
+    mut stop: impl FnMut(Option<u32>, bool) -> bool,
+) -> bool {
+    let mut todos = VecDeque::new();
+    let button = Wire {
+        to: machine.broadcaster,
+        slot: 0,
+    };
+    todos.push_back((false, button, None));
+    while let Some((signal, wire, previous)) = todos.pop_front() {
+        if stop(previous, signal) {
+            return true;
+        }
+        if let Some(next_signal) = update_module(machine, wire, signal, state, pulses) {
+            for &next in &machine.modules[wire.to as usize].outputs {
+                todos.push_back((next_signal, next, Some(wire.to)));
+            }
+        }
+    }
+    false
+}
+
+/// Send a pulse along `wire`, returning the pulse the module sends on, if any.
+fn update_module(
+    machine: &Machine,
+    wire: Wire,
+    input: bool,
+    state: &mut State,
+    pulses: &mut Vec<bool>,
+) -> Option<bool> {
+    pulses.push(input);
+    let module = &machine.modules[wire.to as usize];
+    match module.kind {
+        Kind::Broadcaster => Some(input),
+        Kind::FlipFlop => {
+            if !input {
+                let state = &mut state[module.memory];
+                *state = !*state;
+                Some(*state)
+            } else {
+                None
+            }
+        }
+        Kind::Conjunction => {
+            let memory = &mut state[module.memory..module.memory + module.inputs];
+            memory[wire.slot] = input;
+            Some(!memory.iter().all(|&v| v))
+        }
+        Kind::Sink => None,
+    }
+}
+
+/// The flip-flop and conjunction memories of all modules, side by side.
+type State = Vec<bool>;
+
+/// How the modules are wired together, which never changes while pulses are sent. Modules are
+/// numbered by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    names: Interner,
+    modules: Vec<Module>,
+    broadcaster: u32,
+    /// The length of a [`State`].
+    size: usize,
+}
+
+impl Machine {
+    /// The modules with a wire to module `id`.
+    fn inputs_of(&self, id: u32) -> Vec<u32> {
+        (0..)
+            .zip(&self.modules)
+            .filter(|(_, module)| module.outputs.iter().any(|wire| wire.to == id))
+            .map(|(from, _)| from)
+            .collect()
+    }
+
+    fn initial_state(&self) -> State {
+        vec![false; self.size]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Module {
+    kind: Kind,
+    outputs: Vec<Wire>,
+    /// Where the memory of this module starts in a [`State`]: one bit for a flip-flop, one per
+    /// input for a conjunction.
+    memory: usize,
+    inputs: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    /// A module that only receives pulses, like `output`.
+    Sink,
+}
+
+/// A connection to the module `to`, as its input number `slot`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Wire {
+    to: u32,
+    slot: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_day20p1_example1() {
+        let input = r#"
+            broadcaster -> a, b, c
+            %a -> b
+            %b -> c
+            %c -> inv
+            &inv -> a
+        "#;
+        assert_eq!(part1(&parse_modules(input).unwrap()), 32000000);
+    }
+
+    #[test]
+    pub fn test_day20p1_example2() {
+        let input = r#"
+            broadcaster -> a
+            %a -> inv, con
+            &inv -> b
+            %b -> con
+            &con -> output
+        "#;
+        assert_eq!(part1(&parse_modules(input).unwrap()), 11687500);
+    }
+
+    #[test]
+    pub fn test_day20p1_long_period() {
+        // A 40 bit counter, which only returns to its first state after 2^40 presses
+        let mut input = "broadcaster -> a0\n".to_string();
+        for bit in 0..40 {
+            input += &format!("%a{bit} -> a{}\n", bit + 1);
+        }
+        // Every press sends the button's and broadcaster's low pulses, a low pulse for every bit
+        // turned off and a high one for the bit turned on
+        let low: usize = (0..1000_usize)
+            .map(|n| 2 + n.trailing_ones() as usize)
+            .sum();
+        assert_eq!(part1(&parse_modules(&input).unwrap()), low * 1000);
+    }
+
+    #[test]
+    pub fn test_day20p2_missing_modules() {
+        // No rx, rx fed by a flip-flop, and an input of the conjunction that never sends a pulse
+        let input = "broadcaster -> a\n%a -> con\n&con -> output";
+        assert_eq!(part2(&parse_modules(input).unwrap()), None);
+        let input = "broadcaster -> a\n%a -> rx";
+        assert_eq!(part2(&parse_modules(input).unwrap()), None);
+        let input = "broadcaster -> a\n%a -> con\n%b -> con\n&con -> rx";
+        assert_eq!(part2(&parse_modules(input).unwrap()), None);
+
+        // A counter, with dd, fh, xp and fc turning on after 1, 2, 4 and 8 presses
+        let input = r#"
+            broadcaster -> dd, a
+            %a -> fh, b
+            %b -> xp, c
+            %c -> fc
+            %dd -> con
+            %fh -> con
+            %xp -> con
+            %fc -> con
+            &con -> rx
+        "#;
+        assert_eq!(part2(&parse_modules(input).unwrap()), Some(8));
+    }
+
+    #[test]
+    pub fn test_day20_chained_conjunctions() {
+        let input = r#"
+            broadcaster -> a
+            %a -> x
+            &x -> y
+            &y -> output
+        "#;
+        let machine = parse_modules(input).unwrap();
+        let y = machine.names.get("y").unwrap();
+        assert_eq!(machine.modules[y as usize].inputs, 1);
+
+        let mut state = machine.initial_state();
+        let mut pulses = Vec::new();
+        tick(&machine, &mut state, &mut pulses);
+        assert_eq!(pulses, vec![false, false, true, false, true]);
+
+        let mut pulses = Vec::new();
+        let x = machine.names.get("x").unwrap();
+        assert!(tick_until(&machine, &mut state, &mut pulses, x, true));
+        assert_eq!(pulses, vec![false, false, false]);
+    }
+
+    #[test]
+    pub fn test_day20_parse_error() {
+        let input = "broadcaster -> a\n%a -> inv, con\ninv -> b";
+        let err = parse_modules(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (3, 1, "inv"));
+        let input = "broadcaster -> a\n%a";
+        let err = parse_modules(input).unwrap_err().locate(input);
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 3, "` -> `")
+        );
+    }
+}
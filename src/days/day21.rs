@@ -0,0 +1,186 @@
+#![allow(unused)]
+
+use std::collections::{BTreeSet, HashSet};
+
+use num::Integer;
+
+use crate::geometry::Coord;
+use crate::grid::Grid;
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (Map, Coord);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1((map, start): &(Map, Coord)) -> usize {
+    get_positions(map, *start, 64, false).len()
+}
+
+fn part2((map, start): &(Map, Coord)) -> usize {
+    get_num_repeating_positions(map, *start, 26501365)
+}
+
+type Map = Grid<bool>;
+
+fn parse_map(input: &str) -> Result<(Map, Coord), ParseError> {
+    let chars = Grid::parse_with(input, |c| ".#S".contains(c).then_some(c), "#, . or S")?;
+    let Some(start) = chars.find(&'S') else {
+        return Err(ParseError::after(input.trim_end(), "a start tile `S`"));
+    };
+    Ok((chars.map(|&c| c != '#'), start))
+}
+
+fn get_positions(map: &Map, start: Coord, steps: usize, repeat: bool) -> BTreeSet<Coord> {
+    let mut todo = steps;
+    let mut positions = BTreeSet::new();
+    positions.insert(start);
+
+    while todo > 0 {
+        // Get neighbours for coords that were new in the previous iteration
+        let mut new_positions = BTreeSet::new();
+        for pos in positions {
+            new_positions.extend(step(map, pos, repeat));
+        }
+        positions = new_positions;
+        todo -= 1;
+    }
+
+    positions
+}
+
+fn step(map: &Map, curr: Coord, repeat: bool) -> BTreeSet<Coord> {
+    if repeat {
+        let tiled = map.tiled().expect("Parsed maps have tiles");
+        tiled.neighbours(curr).filter(|&next| tiled[next]).collect()
+    } else {
+        map.neighbours(curr).filter(|&next| map[next]).collect()
+    }
+}
+
+/// The number of garden plots reachable in exactly `steps` steps on the infinitely repeating map.
+///
+/// Once the reachable area covers a few copies of the map, it grows by the same pattern of
+/// copies every `period` steps, so the counts at `steps % period`, `steps % period + period`, ...
+/// become quadratic. Walk until they are, then extrapolate.
+fn get_num_repeating_positions(map: &Map, start: Coord, steps: usize) -> usize {
+    let period = map.width().lcm(&map.height());
+    let offset = steps % period;
+    let tiled = map.tiled().expect("Parsed maps have tiles");
+
+    // Plots first reached after an even and an odd number of steps, which alternate
+    let mut reached = [0, 0];
+    let mut samples = Vec::new();
+    let mut seen = HashSet::from([start]);
+    let mut frontier = vec![start];
+    for walked in 0.. {
+        reached[walked % 2] += frontier.len();
+        if walked == steps {
+            break;
+        }
+        if walked % period == offset {
+            samples.push(reached[walked % 2]);
+            if let Some(count) = extrapolate(&samples, (steps - offset) / period) {
+                return count;
+            }
+        }
+
+        let mut next = Vec::new();
+        for coord in frontier {
+            for neighbour in tiled.neighbours(coord) {
+                if tiled[neighbour] && seen.insert(neighbour) {
+                    next.push(neighbour);
+                }
+            }
+        }
+        frontier = next;
+    }
+    reached[steps % 2]
+}
+
+/// The amount of equal second differences at the end of the samples to take them as quadratic.
+const STABLE: usize = 4;
+
+/// Sample `n`, if the samples have become quadratic.
+fn extrapolate(samples: &[usize], n: usize) -> Option<usize> {
+    if samples.len() < STABLE + 2 {
+        return None;
+    }
+    let last: Vec<i128> = samples[samples.len() - STABLE - 2..]
+        .iter()
+        .map(|&sample| sample as i128)
+        .collect();
+    let second: Vec<i128> = last.windows(3).map(|w| w[2] - 2 * w[1] + w[0]).collect();
+    if second.iter().any(|&d| d != second[0]) {
+        return None;
+    }
+    let k = (n - (samples.len() - 1)) as i128;
+    let (value, delta) = (last[STABLE + 1], last[STABLE + 1] - last[STABLE]);
+    usize::try_from(value + k * delta + second[0] * k * (k + 1) / 2).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_day21p1_example() {
+        let input = r#"
+            ...........
+            .....###.#.
+            .###.##..#.
+            ..#.#...#..
+            ....#.#....
+            .##..S####.
+            .##..#...#.
+            .......##..
+            .##.#.####.
+            .##..##.##.
+            ...........
+        "#;
+        let (map, start) = parse_map(input).unwrap();
+        assert_eq!(get_positions(&map, start, 1, false).len(), 2);
+        assert_eq!(get_positions(&map, start, 2, false).len(), 4);
+        assert_eq!(get_positions(&map, start, 3, false).len(), 6);
+        assert_eq!(get_positions(&map, start, 6, false).len(), 16);
+    }
+
+    #[test]
+    pub fn test_day21p2_example() {
+        let input = r#"
+            ...........
+            .....###.#.
+            .###.##..#.
+            ..#.#...#..
+            ....#.#....
+            .##..S####.
+            .##..#...#.
+            .......##..
+            .##.#.####.
+            .##..##.##.
+            ...........
+        "#;
+        let (map, start) = parse_map(input).unwrap();
+        assert_eq!(get_num_repeating_positions(&map, start, 6), 16);
+        assert_eq!(get_num_repeating_positions(&map, start, 10), 50);
+        assert_eq!(get_num_repeating_positions(&map, start, 50), 1594);
+        assert_eq!(get_num_repeating_positions(&map, start, 100), 6536);
+        assert_eq!(get_num_repeating_positions(&map, start, 500), 167004);
+        assert_eq!(get_num_repeating_positions(&map, start, 1000), 668697);
+        assert_eq!(get_num_repeating_positions(&map, start, 5000), 16733044);
+    }
+}
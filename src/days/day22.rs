@@ -2,16 +2,30 @@
 
 use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
 
-fn main() {
-    let input = include_str!("./day22.in");
-    let result = part2(input);
-    println!("{result}");
+use crate::parsing::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Brick>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
 
-fn part1(input: &str) -> usize {
+fn part1(bricks: &[Brick]) -> usize {
     let mut safe_bricks: BTreeSet<usize> = BTreeSet::new();
-    let bricks = parse_input(input);
-    let bricks = drop_all_bricks(&bricks);
+    let bricks = drop_all_bricks(bricks);
     let mut supports: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
     let mut supporting: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
     for &brick in bricks.iter() {
@@ -33,21 +47,18 @@ fn part1(input: &str) -> usize {
             Some(list) if list.is_empty() => {
                 safe_bricks.insert(brick.id);
             }
-            Some(list) => {
-                if list.iter().all(|&b| supports.get(&b).unwrap().len() > 1) {
-                    safe_bricks.insert(brick.id);
-                }
+            Some(list) if list.iter().all(|&b| supports.get(&b).unwrap().len() > 1) => {
+                safe_bricks.insert(brick.id);
             }
-            None => (),
+            _ => (),
         }
     }
     println!("Safe bricks: {safe_bricks:?}");
     safe_bricks.len()
 }
 
-fn part2(input: &str) -> usize {
-    let bricks = parse_input(input);
-    let bricks = drop_all_bricks(&bricks);
+fn part2(bricks: &[Brick]) -> usize {
+    let bricks = drop_all_bricks(bricks);
     let mut supports: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
     let mut supporting: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
     for &brick in bricks.iter() {
@@ -146,43 +157,33 @@ fn find_bricks_colliding(brick: Brick, bricks: &[Brick]) -> Vec<&Brick> {
         .collect()
 }
 
-fn parse_input(input: &str) -> Vec<Brick> {
+fn parse_input(input: &str) -> Result<Vec<Brick>, ParseError> {
     input
         .trim()
         .lines()
         .filter_map(|line| (!line.trim().is_empty()).then_some(line.trim()))
         .enumerate()
         .map(|(id, line)| {
-            let mut coords = line.split('~');
-            let start = coords
-                .next()
-                .unwrap()
-                .split(',')
-                .map(|c| c.parse::<usize>().unwrap())
-                .collect::<Vec<_>>();
-            let end = coords
-                .next()
-                .unwrap()
-                .split(',')
-                .map(|c| c.parse::<usize>().unwrap())
-                .collect::<Vec<_>>();
-            Brick {
+            let (start, end) = parsing::split_once(line, "~")?;
+            Ok(Brick {
                 id,
-                start: Coord {
-                    x: start[0],
-                    y: start[1],
-                    z: start[2],
-                },
-                end: Coord {
-                    x: end[0],
-                    y: end[1],
-                    z: end[2],
-                },
-            }
+                start: parse_coord(start)?,
+                end: parse_coord(end)?,
+            })
         })
         .collect()
 }
 
+fn parse_coord(input: &str) -> Result<Coord, ParseError> {
+    let (x, yz) = parsing::split_once(input, ",")?;
+    let (y, z) = parsing::split_once(yz, ",")?;
+    Ok(Coord {
+        x: parsing::number(x)?,
+        y: parsing::number(y)?,
+        z: parsing::number(z)?,
+    })
+}
+
 fn drop_all_bricks(bricks: &[Brick]) -> Vec<Brick> {
     let (mut dropped, mut bricks) = drop_bricks_tick(bricks);
     // Keep dropping untill no bricks changed
@@ -232,7 +233,7 @@ struct Coord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Brick {
+pub struct Brick {
     id: usize,
     start: Coord,
     end: Coord,
@@ -253,7 +254,7 @@ mod tests {
             0,1,6~2,1,6
             1,1,8~1,1,9
         "#;
-        assert_eq!(part1(input), 5);
+        assert_eq!(part1(&parse_input(input).unwrap()), 5);
     }
 
     #[test]
@@ -267,7 +268,7 @@ mod tests {
             0,1,6~2,1,6
             1,1,8~1,1,9
         "#;
-        let bricks = parse_input(input);
+        let bricks = parse_input(input).unwrap();
         assert_eq!(
             bricks,
             vec![
@@ -310,7 +311,7 @@ mod tests {
         );
 
         let bricks = drop_all_bricks(&bricks);
-        let solution = vec![
+        let solution = [
             Brick {
                 id: 0,
                 start: Coord { x: 1, y: 0, z: 1 },
@@ -366,6 +367,16 @@ mod tests {
             0,1,6~2,1,6
             1,1,8~1,1,9
         "#;
-        assert_eq!(part2(input), 7);
+        assert_eq!(part2(&parse_input(input).unwrap()), 7);
+    }
+
+    #[test]
+    pub fn test_day22_parse_error() {
+        let input = "1,0,1~1,2,1\n0,0,2~2,0";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 10, "`,`")
+        );
     }
 }
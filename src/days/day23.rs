@@ -0,0 +1,224 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+
+use crate::geometry::{Coord, Direction};
+use crate::grid::Grid;
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        match run1(input) {
+            Some(steps) => steps.into(),
+            None => "There is no hike from the start to the end".into(),
+        }
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        match run2(input) {
+            Some(steps) => steps.into(),
+            None => "There is no hike from the start to the end".into(),
+        }
+    }
+}
+
+type Map = Grid<char>;
+
+fn parse_map(input: &str) -> Result<Map, ParseError> {
+    let map = Grid::parse_with(
+        input,
+        |c| "#.^>v<".contains(c).then_some(c),
+        "#, ., ^, >, v or <",
+    )?;
+    if map.row(0).iter().filter(|&&c| c == '.').count() != 1
+        || map
+            .row(map.height() - 1)
+            .iter()
+            .filter(|&&c| c == '.')
+            .count()
+            != 1
+    {
+        return Err(ParseError::after(
+            input.trim_end(),
+            "a single path tile in the top and bottom rows",
+        ));
+    }
+    Ok(map)
+}
+
+fn run1(map: &Map) -> Option<usize> {
+    longest_hike(&Trails::new(map, true))
+}
+
+fn run2(map: &Map) -> Option<usize> {
+    longest_hike(&Trails::new(map, false))
+}
+
+/// The map compressed into a graph of junctions, connected by the corridors between them.
+#[derive(Debug)]
+struct Trails {
+    /// For every junction, the junctions it leads to and the length of the corridor there.
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    end: usize,
+}
+
+impl Trails {
+    /// Find the junctions (the start, the end, and every tile where paths split) and walk the
+    /// corridors between them. With `slippery` slopes, corridors can only be walked downhill.
+    fn new(map: &Map, slippery: bool) -> Self {
+        let open = |coord: Coord| map.get(coord).is_some_and(|&c| c != '#');
+        let start = map.find(&'.').unwrap();
+        let end = (
+            map.row(map.height() - 1)
+                .iter()
+                .position(|&c| c == '.')
+                .unwrap() as i64,
+            map.height() as i64 - 1,
+        );
+        let junctions: Vec<Coord> = map
+            .coords()
+            .filter(|&coord| {
+                coord == start
+                    || coord == end
+                    || (open(coord) && map.neighbours(coord).filter(|&n| open(n)).count() > 2)
+            })
+            .collect();
+        let ids: HashMap<Coord, usize> =
+            junctions.iter().enumerate().map(|(i, &c)| (c, i)).collect();
+
+        // A step is allowed onto any open tile, but never against the slope of the tile it
+        // leaves when slopes are slippery
+        let can_step = |from: Coord, to: Coord| {
+            open(to)
+                && (!slippery
+                    || match map[from] {
+                        '^' => Direction::Up.step(from, 1) == to,
+                        '>' => Direction::Right.step(from, 1) == to,
+                        'v' => Direction::Down.step(from, 1) == to,
+                        '<' => Direction::Left.step(from, 1) == to,
+                        _ => true,
+                    })
+        };
+
+        let mut edges = vec![Vec::new(); junctions.len()];
+        for (id, &junction) in junctions.iter().enumerate() {
+            for first in map.neighbours(junction) {
+                if !can_step(junction, first) {
+                    continue;
+                }
+                let (mut previous, mut current, mut length) = (junction, first, 1);
+                // Follow the corridor until the next junction, or a dead end
+                loop {
+                    if let Some(&other) = ids.get(&current) {
+                        edges[id].push((other, length));
+                        break;
+                    }
+                    let Some(next) = map
+                        .neighbours(current)
+                        .find(|&next| next != previous && can_step(current, next))
+                    else {
+                        break;
+                    };
+                    (previous, current, length) = (current, next, length + 1);
+                }
+            }
+        }
+        Trails {
+            edges,
+            start: ids[&start],
+            end: ids[&end],
+        }
+    }
+}
+
+/// The length of the longest hike from the start to the end that never visits a tile twice, or
+/// `None` if the end can't be reached.
+fn longest_hike(trails: &Trails) -> Option<usize> {
+    let mut visited = vec![false; trails.edges.len()];
+    visited[trails.start] = true;
+    longest_from(trails, trails.start, &mut visited)
+}
+
+/// The longest hike from `junction` to the end, avoiding the junctions in `visited`. `None` if
+/// the end can't be reached.
+fn longest_from(trails: &Trails, junction: usize, visited: &mut [bool]) -> Option<usize> {
+    if junction == trails.end {
+        return Some(0);
+    }
+    let mut longest = None;
+    for &(next, length) in &trails.edges[junction] {
+        if visited[next] {
+            continue;
+        }
+        visited[next] = true;
+        if let Some(rest) = longest_from(trails, next, visited) {
+            longest = longest.max(Some(rest + length));
+        }
+        visited[next] = false;
+    }
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("fixtures/day23.txt");
+
+    #[test]
+    fn test_day23p1_example() {
+        assert_eq!(run1(&parse_map(EXAMPLE).unwrap()), Some(94));
+    }
+
+    #[test]
+    fn test_day23p2_example() {
+        assert_eq!(run2(&parse_map(EXAMPLE).unwrap()), Some(154));
+    }
+
+    #[test]
+    fn test_day23_trails() {
+        let map = parse_map(EXAMPLE).unwrap();
+        let trails = Trails::new(&map, false);
+        // The start, the end and 7 junctions in between
+        assert_eq!(trails.edges.len(), 9);
+        let (first, length) = trails.edges[trails.start][0];
+        assert_eq!(length, 15);
+        assert!(trails.edges[first].contains(&(trails.start, 15)));
+        let slippery = Trails::new(&map, true);
+        let corridors = |trails: &Trails| trails.edges.iter().map(Vec::len).sum::<usize>();
+        assert_eq!(corridors(&slippery) * 2, corridors(&trails));
+    }
+
+    #[test]
+    fn test_day23_many_junctions() {
+        // A single path down, with a dead end to the right on every other tile
+        let mut rows = vec!["#.##"; 2];
+        for _ in 0..100 {
+            rows.extend(["#..#", "#.##"]);
+        }
+        let map = parse_map(&rows.join("\n")).unwrap();
+        assert!(Trails::new(&map, false).edges.len() > 64);
+        assert_eq!(run2(&map), Some(map.height() - 1));
+
+        let map = parse_map("#.###\n#.#.#\n###.#\n#####\n###.#").unwrap();
+        assert_eq!(run1(&map), None);
+    }
+
+    #[test]
+    fn test_day23_parse_error() {
+        let input = "#.#\n#x#\n#.#";
+        let err = parse_map(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "x"));
+        assert!(parse_map("#.#\n#.#\n###").is_err());
+    }
+}
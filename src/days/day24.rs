@@ -0,0 +1,244 @@
+#![allow(unused)]
+
+use std::ops::RangeInclusive;
+
+use num::{BigInt, BigRational, Signed, Zero};
+
+use crate::parsing::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Hailstone>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input, 200_000_000_000_000..=400_000_000_000_000).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        match run2(input) {
+            Some(sum) => sum.into(),
+            None => "No throw hits every hailstone".into(),
+        }
+    }
+}
+
+type Vec3 = [i64; 3];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hailstone {
+    position: Vec3,
+    velocity: Vec3,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (position, velocity) = parsing::split_once(line, " @ ")?;
+            Ok(Hailstone {
+                position: parse_vec3(position)?,
+                velocity: parse_vec3(velocity)?,
+            })
+        })
+        .collect()
+}
+
+fn parse_vec3(s: &str) -> Result<Vec3, ParseError> {
+    let numbers = s
+        .split(',')
+        .map(|n| parsing::number(n.trim()))
+        .collect::<Result<Vec<i64>, _>>()?;
+    numbers
+        .try_into()
+        .map_err(|_| ParseError::new(s, "3 numbers separated by commas"))
+}
+
+/// Count the pairs of hailstones whose paths cross inside the test area, ignoring the z axis.
+fn run1(hailstones: &[Hailstone], area: RangeInclusive<i64>) -> usize {
+    let mut count = 0;
+    for (i, a) in hailstones.iter().enumerate() {
+        for b in &hailstones[i + 1..] {
+            if crosses_within(a, b, &area) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Whether the future paths of `a` and `b` cross inside `area` on the x and y axes.
+fn crosses_within(a: &Hailstone, b: &Hailstone, area: &RangeInclusive<i64>) -> bool {
+    let cross = |u: [i128; 2], v: [i128; 2]| u[0] * v[1] - u[1] * v[0];
+    let [px, py, _] = a.position.map(i128::from);
+    let [vx, vy, _] = a.velocity.map(i128::from);
+    let [qx, qy, _] = b.position.map(i128::from);
+    let [wx, wy, _] = b.velocity.map(i128::from);
+
+    // The paths cross at `a` after `t = ta / d` and at `b` after `s = tb / d` nanoseconds.
+    // Parallel paths never cross.
+    let mut d = cross([vx, vy], [wx, wy]);
+    if d == 0 {
+        return false;
+    }
+    let offset = [qx - px, qy - py];
+    let mut ta = cross(offset, [wx, wy]);
+    let mut tb = cross(offset, [vx, vy]);
+    if d < 0 {
+        (d, ta, tb) = (-d, -ta, -tb);
+    }
+    if ta < 0 || tb < 0 {
+        // They crossed in the past
+        return false;
+    }
+
+    // Compare the crossing point, `p + v * t`, to the area scaled by `d` to stay exact
+    let (low, high) = (i128::from(*area.start()) * d, i128::from(*area.end()) * d);
+    let x = px * d + vx * ta;
+    let y = py * d + vy * ta;
+    (low..=high).contains(&x) && (low..=high).contains(&y)
+}
+
+/// The sum of the coordinates of the position to throw a rock from, so it hits every hailstone.
+fn run2(hailstones: &[Hailstone]) -> Option<BigInt> {
+    let (position, _) = find_rock(hailstones)?;
+    Some(position.iter().sum())
+}
+
+/// The position and velocity of a rock that hits every hailstone.
+///
+/// The rock at `P` moving at `V` hits hailstone `i` when `(P - p_i) × (V - v_i) = 0`. The only
+/// nonlinear term in that is `P × V`, which is the same for all hailstones, so subtracting the
+/// equations of two hailstones gives three linear ones:
+/// `P × (v_j - v_i) + (p_j - p_i) × V = p_j × v_j - p_i × v_i`. Two pairs of hailstones are then
+/// enough to solve for `P` and `V` exactly.
+fn find_rock(hailstones: &[Hailstone]) -> Option<([BigInt; 3], [BigInt; 3])> {
+    let big = |v: Vec3| v.map(BigInt::from);
+    let sub = |a: &[BigInt; 3], b: &[BigInt; 3]| [0, 1, 2].map(|k| &a[k] - &b[k]);
+    let cross = |a: &[BigInt; 3], b: &[BigInt; 3]| {
+        [
+            &a[1] * &b[2] - &a[2] * &b[1],
+            &a[2] * &b[0] - &a[0] * &b[2],
+            &a[0] * &b[1] - &a[1] * &b[0],
+        ]
+    };
+
+    // Some hailstones give dependent equations, so try others until the system can be solved
+    let first = hailstones.first()?;
+    let (p0, v0) = (big(first.position), big(first.velocity));
+    for (j, b) in hailstones.iter().enumerate().skip(1) {
+        for c in &hailstones[j + 1..] {
+            let mut rows = Vec::new();
+            for other in [b, c] {
+                let (p, v) = (big(other.position), big(other.velocity));
+                let [wx, wy, wz] = sub(&v, &v0);
+                let [ux, uy, uz] = sub(&p, &p0);
+                let rhs = sub(&cross(&p, &v), &cross(&p0, &v0));
+                let zero = BigInt::zero;
+                let coefficients = [
+                    [zero(), wz.clone(), -&wy, zero(), -&uz, uy.clone()],
+                    [-&wz, zero(), wx.clone(), uz, zero(), -&ux],
+                    [wy, -wx, zero(), -uy, ux, zero()],
+                ];
+                for (coefficients, rhs) in coefficients.into_iter().zip(rhs) {
+                    rows.push((coefficients, rhs));
+                }
+            }
+            let Some(solution) = solve(rows) else {
+                continue;
+            };
+            if solution.iter().all(|x| x.is_integer()) {
+                let solution = solution.map(|x| x.to_integer());
+                let [px, py, pz, vx, vy, vz] = solution;
+                return Some(([px, py, pz], [vx, vy, vz]));
+            }
+        }
+    }
+    None
+}
+
+/// Solve a system of 6 linear equations exactly with Gaussian elimination. `None` if it has no
+/// single solution.
+fn solve(rows: Vec<([BigInt; 6], BigInt)>) -> Option<[BigRational; 6]> {
+    let mut matrix: Vec<Vec<BigRational>> = rows
+        .into_iter()
+        .map(|(coefficients, rhs)| {
+            coefficients
+                .into_iter()
+                .chain([rhs])
+                .map(BigRational::from_integer)
+                .collect()
+        })
+        .collect();
+    let n = matrix.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|&row| !matrix[row][col].is_zero())?;
+        matrix.swap(col, pivot);
+        let pivot_row = matrix[col].clone();
+        for (i, row) in matrix.iter_mut().enumerate() {
+            if i != col && !row[col].is_zero() {
+                let factor = &row[col] / &pivot_row[col];
+                for (value, pivot_value) in row.iter_mut().zip(&pivot_row).skip(col) {
+                    *value -= &factor * pivot_value;
+                }
+            }
+        }
+    }
+    let solution: Vec<BigRational> = (0..n).map(|i| &matrix[i][n] / &matrix[i][i]).collect();
+    solution.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("fixtures/day24.txt");
+
+    #[test]
+    fn test_day24p1_example() {
+        let hailstones = parse_input(EXAMPLE).unwrap();
+        assert_eq!(run1(&hailstones, 7..=27), 2);
+        assert!(crosses_within(&hailstones[0], &hailstones[1], &(7..=27)));
+        // Parallel paths
+        assert!(!crosses_within(&hailstones[1], &hailstones[2], &(7..=27)));
+        // Crossed in the past
+        assert!(!crosses_within(&hailstones[0], &hailstones[4], &(7..=27)));
+    }
+
+    #[test]
+    fn test_day24p2_example() {
+        let hailstones = parse_input(EXAMPLE).unwrap();
+        let (position, velocity) = find_rock(&hailstones).unwrap();
+        assert_eq!(position, [24, 13, 10].map(BigInt::from));
+        assert_eq!(velocity, [-3, 1, 2].map(BigInt::from));
+        assert_eq!(run2(&hailstones), Some(BigInt::from(47)));
+    }
+
+    #[test]
+    fn test_day24_large_numbers() {
+        let input = r#"
+            200000000000000, 300000000000000, 100000000000000 @ 1, 0, 0
+            400000000000000, 100000000000000, 100000000000000 @ 0, 1, 0
+        "#;
+        let hailstones = parse_input(input).unwrap();
+        let area = 200_000_000_000_000..=400_000_000_000_000;
+        assert_eq!(run1(&hailstones, area), 1);
+        // Two hailstones don't pin down a single throw
+        assert_eq!(run2(&hailstones), None);
+    }
+
+    #[test]
+    fn test_day24_parse_error() {
+        let input = "19, 13, 30 @ -2, 1, -2\n18, 19 @ -1, -1, -2";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "18, 19"));
+    }
+}
@@ -0,0 +1,162 @@
+#![allow(unused)]
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::parsing::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Components;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        match run1(input) {
+            Some(product) => product.into(),
+            None => "No 3 wires split the components in two".into(),
+        }
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        // There is no puzzle for part 2, only the button to push once all other stars are in
+        "Merry Christmas!".into()
+    }
+}
+
+/// The wiring diagram: every component is numbered, and wires connect two components.
+#[derive(Debug, Clone, Default)]
+pub struct Components {
+    /// For every component, its wires as (index in `wires`, other component).
+    connections: Vec<Vec<(usize, usize)>>,
+    wires: Vec<(usize, usize)>,
+}
+
+fn parse_input(input: &str) -> Result<Components, ParseError> {
+    let mut ids: HashMap<&str, usize> = HashMap::new();
+    let mut components = Components::default();
+    let mut id = |name, components: &mut Components| {
+        *ids.entry(name).or_insert_with(|| {
+            components.connections.push(Vec::new());
+            components.connections.len() - 1
+        })
+    };
+    for line in input
+        .trim()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+    {
+        let (name, others) = parsing::split_once(line, ": ")?;
+        let from = id(name, &mut components);
+        for other in others.split_whitespace() {
+            let to = id(other, &mut components);
+            let wire = components.wires.len();
+            components.wires.push((from, to));
+            components.connections[from].push((wire, to));
+            components.connections[to].push((wire, from));
+        }
+    }
+    Ok(components)
+}
+
+/// Cut 3 wires to split the components into two groups, and multiply the groups' sizes.
+fn run1(components: &Components) -> Option<usize> {
+    let (a, b) = cut(components, 3)?;
+    Some(a * b)
+}
+
+/// Find the sizes of the two groups the components fall apart in when cutting `wires` wires,
+/// if that is the minimum cut between them.
+///
+/// By the max-flow min-cut theorem, two components end up in different groups exactly when
+/// only `wires` separate paths connect them. So fix one component, and look for another one
+/// it has that many paths to. The components it can still reach after using those paths are its
+/// group.
+fn cut(components: &Components, wires: usize) -> Option<(usize, usize)> {
+    let count = components.connections.len();
+    (1..count).find_map(|sink| {
+        let (paths, group) = max_flow(components, 0, sink, wires + 1);
+        (paths == wires).then_some((group, count - group))
+    })
+}
+
+/// The amount of separate paths from `source` to `sink`, up to `limit`, and the amount of
+/// components that can still be reached from `source` when they are all in use.
+fn max_flow(components: &Components, source: usize, sink: usize, limit: usize) -> (usize, usize) {
+    // The flow through every wire: 1 from its first to its second component, -1 the other way
+    let mut flow = vec![0_i8; components.wires.len()];
+    let mut paths = 0;
+    loop {
+        // Breadth first search for a path through wires that still have room for more flow
+        let mut previous: Vec<Option<(usize, usize)>> = vec![None; components.connections.len()];
+        let mut reached = vec![false; components.connections.len()];
+        reached[source] = true;
+        let mut todo = VecDeque::from([source]);
+        while let Some(component) = todo.pop_front() {
+            for &(wire, next) in &components.connections[component] {
+                let forward = components.wires[wire].0 == component;
+                let room = if forward {
+                    flow[wire] < 1
+                } else {
+                    flow[wire] > -1
+                };
+                if room && !reached[next] {
+                    reached[next] = true;
+                    previous[next] = Some((wire, component));
+                    todo.push_back(next);
+                }
+            }
+        }
+        if !reached[sink] || paths == limit {
+            let group = reached.iter().filter(|&&r| r).count();
+            return (paths, group);
+        }
+
+        let mut component = sink;
+        while let Some((wire, from)) = previous[component] {
+            flow[wire] += if components.wires[wire].0 == from {
+                1
+            } else {
+                -1
+            };
+            component = from;
+        }
+        paths += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("fixtures/day25.txt");
+
+    #[test]
+    fn test_day25p1_example() {
+        let components = parse_input(EXAMPLE).unwrap();
+        assert_eq!(components.connections.len(), 15);
+        assert_eq!(components.wires.len(), 33);
+        assert_eq!(run1(&components), Some(54));
+    }
+
+    #[test]
+    fn test_day25_cut() {
+        // Two triangles joined by a single wire
+        let input = "a: b c\nb: c\nc: d\nd: e f\ne: f";
+        let components = parse_input(input).unwrap();
+        assert_eq!(cut(&components, 1), Some((3, 3)));
+        assert_eq!(cut(&components, 3), None);
+        assert_eq!(run1(&components), None);
+    }
+
+    #[test]
+    fn test_day25_parse_error() {
+        let input = "jqt: rhn xhk nvd\nrsh frs pzl lsr";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.expected.as_str()), (2, "`: `"));
+    }
+}
@@ -0,0 +1,101 @@
+//! Directions and coordinates on a 2D plane.
+//!
+//! Coordinates are `(x, y)` pairs where `y` grows downwards, matching the way puzzle maps are
+//! read line by line.
+
+pub type Coord = (i64, i64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The `(dx, dy)` step for a single move in this direction.
+    pub fn delta(self) -> Coord {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Move `steps` tiles from `coord` in this direction.
+    pub fn step(self, coord: Coord, steps: i64) -> Coord {
+        let (dx, dy) = self.delta();
+        (coord.0 + dx * steps, coord.1 + dy * steps)
+    }
+}
+
+/// The taxicab distance between two coordinates.
+pub fn manhattan(a: Coord, b: Coord) -> u64 {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.opposite().opposite(), dir);
+        }
+    }
+
+    #[test]
+    fn test_direction_step() {
+        assert_eq!(Direction::Up.step((3, 3), 2), (3, 1));
+        assert_eq!(Direction::Right.step((3, 3), 2), (5, 3));
+        assert_eq!(Direction::Down.step((3, 3), 2), (3, 5));
+        assert_eq!(Direction::Left.step((3, 3), 2), (1, 3));
+    }
+
+    #[test]
+    fn test_manhattan() {
+        assert_eq!(manhattan((1, 6), (5, 11)), 9);
+        assert_eq!(manhattan((5, 11), (1, 6)), 9);
+        assert_eq!(manhattan((-2, -2), (2, 2)), 8);
+    }
+}
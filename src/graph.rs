@@ -0,0 +1,275 @@
+//! Graph traversals over tile maps and implicit graphs.
+//!
+//! All traversals are iterative, using a queue instead of recursion, so they can't overflow the
+//! stack on large inputs. Their memory use is bounded by the amount of nodes they visit.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use num::Zero;
+
+use crate::geometry::Coord;
+use crate::grid::Grid;
+
+/// All nodes reachable from `start` (included), where `neighbours` gives the nodes that can be
+/// moved to from a node. This works on any implicit graph: filter out impassable nodes in
+/// `neighbours`.
+pub fn reachable<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut todo = VecDeque::from([start]);
+    while let Some(node) = todo.pop_front() {
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                todo.push_back(next);
+            }
+        }
+    }
+    seen
+}
+
+/// The coordinates of all tiles reachable from `start` by moving up, down, left or right through
+/// tiles for which `passable` returns true. `start` itself is always included, even if it isn't
+/// passable.
+pub fn region<T>(map: &Grid<T>, start: Coord, passable: impl Fn(&T) -> bool) -> HashSet<Coord> {
+    reachable(start, |&coord| {
+        map.neighbours(coord)
+            .filter(|&next| passable(&map[next]))
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Flood fill the map with `value`, starting from the specified coordinate.
+/// Only tiles for which `fillable` returns true will be replaced or expanded into.
+pub fn flood_fill<T: Clone>(
+    map: &mut Grid<T>,
+    start: Coord,
+    value: &T,
+    fillable: impl Fn(&T) -> bool,
+) {
+    for coord in region(map, start, &fillable) {
+        let tile = &mut map[coord];
+        if fillable(tile) {
+            *tile = value.clone();
+        }
+    }
+}
+
+/// The connected regions of passable tiles in a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// For every tile, the index of the region it belongs to, `None` if it isn't passable.
+    pub labels: Grid<Option<usize>>,
+    /// The amount of tiles in each region, in the order they were found scanning row by row.
+    pub sizes: Vec<usize>,
+}
+
+/// Label the regions of tiles for which `passable` returns true that are connected up, down,
+/// left or right.
+pub fn components<T>(map: &Grid<T>, passable: impl Fn(&T) -> bool) -> Components {
+    let mut labels = map.map(|_| None);
+    let mut sizes = Vec::new();
+    let mut todo = VecDeque::new();
+    for (start, tile) in map.iter() {
+        if labels[start].is_some() || !passable(tile) {
+            continue;
+        }
+        let label = sizes.len();
+        let mut size = 0;
+        labels[start] = Some(label);
+        todo.push_back(start);
+        while let Some(coord) = todo.pop_front() {
+            size += 1;
+            for next in map.neighbours(coord) {
+                if labels[next].is_none() && passable(&map[next]) {
+                    labels[next] = Some(label);
+                    todo.push_back(next);
+                }
+            }
+        }
+        sizes.push(size);
+    }
+    Components { labels, sizes }
+}
+
+/// A cheapest path found by [`shortest_path`] or [`a_star`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// The states along the path, from the start up to and including the goal.
+    pub states: Vec<S>,
+}
+
+/// Find the cheapest path from `start` to a state for which `is_goal` returns true, using
+/// Dijkstra's algorithm. `neighbours` gives the states that can be moved to from a state, with
+/// the cost of each move. Returns `None` if no goal can be reached.
+pub fn shortest_path<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    a_star(start, neighbours, is_goal, |_| C::zero())
+}
+
+/// Like [`shortest_path`], but guided by a `heuristic` estimating the remaining cost from a
+/// state to the goal. The heuristic must never overestimate that cost, or the path found might
+/// not be the cheapest.
+pub fn a_star<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // States are numbered in the order they are found, so the heap doesn't need them to be `Ord`
+    let mut ids: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    let mut costs = vec![C::zero()];
+    let mut previous: Vec<Option<usize>> = vec![None];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&states[0]), C::zero(), 0))]);
+
+    while let Some(Reverse((_, cost, id))) = heap.pop() {
+        if cost > costs[id] {
+            // A cheaper way to this state was found after this one was queued
+            continue;
+        }
+        if is_goal(&states[id]) {
+            let mut path = vec![states[id].clone()];
+            let mut at = id;
+            while let Some(prev) = previous[at] {
+                path.push(states[prev].clone());
+                at = prev;
+            }
+            path.reverse();
+            return Some(Path { cost, states: path });
+        }
+
+        for (next, step) in neighbours(&states[id]) {
+            let next_cost = cost + step;
+            let next_id = match ids.get(&next) {
+                Some(&next_id) if costs[next_id] <= next_cost => continue,
+                Some(&next_id) => {
+                    costs[next_id] = next_cost;
+                    previous[next_id] = Some(id);
+                    next_id
+                }
+                None => {
+                    let next_id = states.len();
+                    ids.insert(next.clone(), next_id);
+                    states.push(next);
+                    costs.push(next_cost);
+                    previous.push(Some(id));
+                    next_id
+                }
+            };
+            let estimate = next_cost + heuristic(&states[next_id]);
+            heap.push(Reverse((estimate, next_cost, next_id)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{manhattan, Direction};
+
+    #[test]
+    fn test_flood_fill() {
+        let input = r#"
+            .....
+            .###.
+            .#.#.
+            .###.
+            .....
+        "#;
+        let mut map: Grid<char> = Grid::parse(input).unwrap();
+        flood_fill(&mut map, (0, 0), &'O', |c| *c == '.');
+        assert_eq!(map.count(&'O'), 16);
+        assert_eq!(map.count(&'.'), 1);
+    }
+
+    #[test]
+    fn test_flood_fill_large() {
+        let mut map = Grid::filled(500, 500, true);
+        flood_fill(&mut map, (250, 250), &false, |tile| *tile);
+        assert_eq!(map.count(&true), 0);
+    }
+
+    #[test]
+    fn test_reachable() {
+        // Every number below 20 that can be reached from 1 by doubling or adding 3
+        let numbers = reachable(1, |&n| [n * 2, n + 3].into_iter().filter(|&n| n < 20));
+        assert_eq!(numbers.len(), 13);
+        assert!(!numbers.contains(&3));
+
+        let map: Grid<char> = Grid::parse("..#\n.##\n#..").unwrap();
+        let coords = reachable((0, 0), |&coord| {
+            Direction::ALL
+                .into_iter()
+                .map(move |dir| dir.step(coord, 1))
+                .filter(|&next| map.get(next) == Some(&'.'))
+        });
+        assert_eq!(coords.len(), 3);
+    }
+
+    #[test]
+    fn test_components() {
+        let input = r#"
+            ..#..
+            ..#.#
+            ###..
+            .#...
+        "#;
+        let map: Grid<char> = Grid::parse(input).unwrap();
+        let components = components(&map, |c| *c == '.');
+        assert_eq!(components.sizes, vec![4, 8, 1]);
+        assert_eq!(components.labels[(0, 0)], Some(0));
+        assert_eq!(components.labels[(4, 3)], Some(1));
+        assert_eq!(components.labels[(0, 3)], Some(2));
+        assert_eq!(components.labels[(2, 0)], None);
+        assert_eq!(region(&map, (3, 0), |c| *c == '.').len(), 8);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let input = r#"
+            131
+            191
+            111
+        "#;
+        let map = Grid::parse_with(input, |c| c.to_digit(10), "a digit").unwrap();
+        let goal = (2, 2);
+        let neighbours = |&coord: &Coord| {
+            map.neighbours(coord)
+                .map(|next| (next, map[next]))
+                .collect::<Vec<_>>()
+        };
+
+        let path = shortest_path((0, 0), neighbours, |&coord| coord == goal).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.states, vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]);
+
+        let heuristic = |&coord: &Coord| manhattan(coord, goal) as u32;
+        let path = a_star((0, 0), neighbours, |&coord| coord == goal, heuristic).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.states.len(), 5);
+
+        let path = shortest_path((1, 1), neighbours, |&coord| coord == (1, 1)).unwrap();
+        assert_eq!((path.cost, path.states), (0, vec![(1, 1)]));
+        assert!(shortest_path((0, 0), neighbours, |&coord| coord == (3, 3)).is_none());
+    }
+}
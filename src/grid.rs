@@ -0,0 +1,510 @@
+//! A 2D tile map, as most puzzle inputs are.
+//!
+//! Tiles are addressed with signed `(x, y)` [`Coord`]s, `x` being the column and `y` the row, so
+//! coordinates that walk off the map simply return `None` instead of underflowing.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::geometry::{Coord, Direction};
+use crate::parsing::ParseError;
+
+/// A tile that can be read from a single character of the puzzle input.
+pub trait FromChar: Sized {
+    /// Describes the valid characters, for parse errors.
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+}
+
+/// A tile that can be written back as a single character.
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+impl FromChar for char {
+    const EXPECTED: &'static str = "a character";
+
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+impl ToChar for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// The tiles, row by row.
+    tiles: Vec<T>,
+}
+
+/// The offsets of the 8 tiles around a tile, clockwise starting from the top.
+const AROUND: [Coord; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+impl<T> Grid<T> {
+    /// A grid of `width` by `height` tiles, all set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            tiles: vec![value; width * height],
+        }
+    }
+
+    /// A grid from its rows. Returns `None` if the rows don't all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height,
+            tiles: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parse a block of text into a grid.
+    /// Leading and trailing whitespace is stripped from the input and from every line.
+    pub fn parse(input: &str) -> Result<Self, ParseError>
+    where
+        T: FromChar,
+    {
+        Self::parse_with(input, T::from_char, T::EXPECTED)
+    }
+
+    /// Parse a block of text into a grid like [`Grid::parse`], converting each character with
+    /// `f`. Fails on characters `f` rejects, described by `expected`, and on lines that are
+    /// shorter or longer than the first one.
+    pub fn parse_with(
+        input: &str,
+        f: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for line in input.trim().lines().map(str::trim) {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| {
+                    f(c).ok_or_else(|| ParseError::new(&line[i..i + c.len_utf8()], expected))
+                })
+                .collect::<Result<Vec<T>, _>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::new(
+                        line,
+                        format!("a line of {} tiles", first.len()),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            return Err(ParseError::new(input, "a grid"));
+        }
+        Ok(Self::from_rows(rows).expect("Rows were checked to have the same length"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, (x, y): Coord) -> Option<usize> {
+        let x = usize::try_from(x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn coord(&self, index: usize) -> Coord {
+        ((index % self.width) as i64, (index / self.width) as i64)
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.index(coord).is_some()
+    }
+
+    /// Get a tile by its coordinates. Returns `None` if out of bounds.
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index(coord).map(|i| &self.tiles[i])
+    }
+
+    /// Get a mutable tile by its coordinates. Returns `None` if out of bounds.
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index(coord).map(|i| &mut self.tiles[i])
+    }
+
+    /// The coordinates of all tiles, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + '_ {
+        (0..self.tiles.len()).map(|i| self.coord(i))
+    }
+
+    /// All tiles with their coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(|(i, tile)| (self.coord(i), tile))
+    }
+
+    /// All tiles, row by row.
+    pub fn tiles(&self) -> impl Iterator<Item = &T> {
+        self.tiles.iter()
+    }
+
+    /// Find the coordinates of the first tile matching `value`, scanning row by row.
+    pub fn find(&self, value: &T) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        self.tiles
+            .iter()
+            .position(|tile| tile == value)
+            .map(|i| self.coord(i))
+    }
+
+    /// Count the amount of tiles equal to `value`.
+    pub fn count(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.tiles.iter().filter(|tile| *tile == value).count()
+    }
+
+    /// The coordinates of the (up to) 4 tiles above, below and beside `coord`.
+    pub fn neighbours(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| dir.step(coord, 1))
+            .filter(|&next| self.contains(next))
+    }
+
+    /// The coordinates of the (up to) 8 tiles around `coord`, diagonals included.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        AROUND
+            .into_iter()
+            .map(move |(dx, dy)| (coord.0 + dx, coord.1 + dy))
+            .filter(|&next| self.contains(next))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.tiles[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        // A grid without columns has no tiles either, any step gives the empty column
+        self.tiles[x..].iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The tiles on the line from `start` (included) taking steps of `delta` until it leaves
+    /// the grid. A delta of `(1, 1)` gives a diagonal, `(-1, 1)` an anti-diagonal.
+    pub fn line(&self, start: Coord, delta: Coord) -> impl Iterator<Item = &T> + '_ {
+        (0..)
+            .map(move |i| (start.0 + delta.0 * i, start.1 + delta.1 * i))
+            .map_while(|coord| self.get(coord))
+    }
+
+    /// View the grid as repeating infinitely in every direction. Returns `None` if the grid has
+    /// no tiles to repeat.
+    pub fn tiled(&self) -> Option<TiledGrid<'_, T>> {
+        TiledGrid::new(self)
+    }
+
+    /// A grid of the same size, with every tile converted by `f`.
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            tiles: self.tiles.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirror the grid along its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let tiles = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            tiles,
+        }
+    }
+
+    /// Rotate the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let tiles = (0..self.width)
+            .flat_map(|x| self.column(x).rev().cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            tiles,
+        }
+    }
+
+    /// Rotate the grid a quarter turn counter-clockwise.
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let tiles = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            tiles,
+        }
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        match self.get(coord) {
+            Some(tile) => tile,
+            None => panic!(
+                "{coord:?} is outside of a {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(coord) {
+            Some(tile) => tile,
+            None => panic!("{coord:?} is outside of a {width}x{height} grid"),
+        }
+    }
+}
+
+/// Renders the grid back to text, one line per row.
+impl<T: ToChar> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().map(ToChar::to_char).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An infinite view of a grid, repeating it in every direction. Any coordinate is valid: it
+/// falls on some copy of the grid, its tile.
+#[derive(Debug, Clone, Copy)]
+pub struct TiledGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> TiledGrid<'a, T> {
+    /// Returns `None` if the grid has no tiles, as coordinates couldn't fall on any.
+    pub fn new(grid: &'a Grid<T>) -> Option<Self> {
+        if grid.tiles.is_empty() {
+            return None;
+        }
+        Some(TiledGrid { grid })
+    }
+
+    /// The grid being repeated.
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// Split `coord` into the matching coordinate within the grid and the index of the tile it
+    /// falls on, with `(0, 0)` being the grid itself and `(-1, 0)` the copy to its left.
+    pub fn locate(&self, (x, y): Coord) -> (Coord, Coord) {
+        let (width, height) = (self.grid.width as i64, self.grid.height as i64);
+        (
+            (x.rem_euclid(width), y.rem_euclid(height)),
+            (x.div_euclid(width), y.div_euclid(height)),
+        )
+    }
+
+    /// The index of the tile `coord` falls on.
+    pub fn tile(&self, coord: Coord) -> Coord {
+        self.locate(coord).1
+    }
+
+    pub fn get(&self, coord: Coord) -> &'a T {
+        &self.grid[self.locate(coord).0]
+    }
+
+    /// The coordinates of the 4 tiles above, below and beside `coord`, which always exist.
+    pub fn neighbours(&self, coord: Coord) -> impl Iterator<Item = Coord> {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| dir.step(coord, 1))
+    }
+}
+
+impl<T> Index<Coord> for TiledGrid<'_, T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        Grid::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let input = r#"
+            #..
+            .#.
+        "#;
+        let grid = grid(input);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.to_string(), "#..\n.#.\n");
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let parse = |input| Grid::parse_with(input, |c| "#.".contains(c).then_some(c), "# or .");
+        assert!(parse("#.\n.#").is_ok());
+        let input = "#.\n.x";
+        let err = parse(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "x"));
+        let input = "#.\n.#.";
+        let err = parse(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.expected.as_str()), (2, "a line of 2 tiles"));
+        assert!(parse("  \n").is_err());
+    }
+
+    #[test]
+    fn test_signed_access() {
+        let mut grid = grid("ab\ncd");
+        assert_eq!(grid.get((1, 0)), Some(&'b'));
+        assert_eq!(grid[(0, 1)], 'c');
+        assert_eq!(grid.get((-1, 0)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        grid[(1, 1)] = 'x';
+        assert_eq!(grid.find(&'x'), Some((1, 1)));
+        assert_eq!(grid.count(&'a'), 1);
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            vec![
+                ((0, 0), &'a'),
+                ((1, 0), &'b'),
+                ((0, 1), &'c'),
+                ((1, 1), &'x')
+            ]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid("abc\ndef\nghi");
+        let around = |coords: Vec<Coord>| -> String { coords.iter().map(|&c| grid[c]).collect() };
+        assert_eq!(around(grid.neighbours((1, 1)).collect()), "bfhd");
+        assert_eq!(around(grid.neighbours((0, 0)).collect()), "bd");
+        assert_eq!(around(grid.neighbours8((1, 1)).collect()), "bcfihgda");
+        assert_eq!(around(grid.neighbours8((2, 2)).collect()), "fhe");
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = grid("abc\ndef\nghi");
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cfi");
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["adg", "beh", "cfi"]
+        );
+        assert_eq!(grid.line((0, 0), (1, 1)).collect::<String>(), "aei");
+        assert_eq!(grid.line((2, 0), (-1, 1)).collect::<String>(), "ceg");
+        assert_eq!(grid.line((1, 2), (0, -1)).collect::<String>(), "heb");
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid("abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+        assert_eq!(grid.map(|&c| c == 'e').count(&true), 1);
+    }
+
+    #[test]
+    fn test_tiled() {
+        let grid = grid("ab\ncd");
+        let tiled = grid.tiled().unwrap();
+        assert_eq!(tiled.locate((1, 1)), ((1, 1), (0, 0)));
+        assert_eq!(tiled.locate((-1, 0)), ((1, 0), (-1, 0)));
+        assert_eq!(tiled.locate((5, -3)), ((1, 1), (2, -2)));
+        assert_eq!(tiled[(-2, -2)], 'a');
+        assert_eq!(tiled.tile((-3, 4)), (-2, 2));
+        let around: String = tiled.neighbours((0, 0)).map(|c| tiled[c]).collect();
+        assert_eq!(around, "cbcb");
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid = Grid::<char>::filled(0, 3, 'a');
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.transpose().rows().count(), 0);
+        assert!(grid.tiled().is_none());
+        assert!(Grid::from_rows(Vec::<Vec<char>>::new())
+            .unwrap()
+            .tiled()
+            .is_none());
+    }
+}
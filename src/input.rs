@@ -0,0 +1,191 @@
+//! Loading puzzle inputs at runtime.
+//!
+//! An input is read from an explicit file, from stdin, or from `dayXX.in` in the inputs
+//! directory. The inputs directory defaults to `inputs/` and can be overridden with the
+//! `AOC_INPUTS_DIR` environment variable.
+
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS_DIR";
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// An explicitly specified file.
+    File(PathBuf),
+    /// Standard input, selected by passing `-` as the path.
+    Stdin,
+    /// The `dayXX.in` file for a day in the inputs directory.
+    Day { dir: PathBuf, day: u8 },
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist.
+    NotFound {
+        path: PathBuf,
+        day: Option<u8>,
+    },
+    /// The input exists but contains nothing but whitespace.
+    Empty(String),
+    Io(String, io::Error),
+}
+
+impl InputSource {
+    /// Pick the input source for a day: an explicit path (or `-` for stdin) wins over the
+    /// inputs directory.
+    pub fn new(path: Option<&str>, dir: &Path, day: u8) -> Self {
+        match path {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::Day {
+                dir: dir.to_path_buf(),
+                day,
+            },
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let input = match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| InputError::Io(self.to_string(), e))?;
+                input
+            }
+            InputSource::File(path) => read_file(path, None)?,
+            InputSource::Day { dir, day } => read_file(&day_path(dir, *day), Some(*day))?,
+        };
+        if input.trim().is_empty() {
+            return Err(InputError::Empty(self.to_string()));
+        }
+        Ok(input)
+    }
+}
+
+fn read_file(path: &Path, day: Option<u8>) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound {
+            path: path.to_path_buf(),
+            day,
+        },
+        _ => InputError::Io(path.display().to_string(), e),
+    })
+}
+
+/// The inputs directory: the `--inputs-dir` argument if given, then the `AOC_INPUTS_DIR`
+/// environment variable, then `inputs/`.
+pub fn inputs_dir(arg: Option<&str>) -> PathBuf {
+    match arg {
+        Some(dir) => PathBuf::from(dir),
+        None => env::var_os(INPUTS_DIR_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUTS_DIR)),
+    }
+}
+
+/// The path of a day's input inside the inputs directory.
+pub fn day_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{day:02}.in"))
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Day { dir, day } => write!(f, "{}", day_path(dir, *day).display()),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound {
+                path,
+                day: Some(day),
+            } => write!(
+                f,
+                "No input found for day {day} at {}. Run `aoc fetch {day}` or save your puzzle \
+                 input there, point {INPUTS_DIR_ENV} at another directory, or pass --input <path>.",
+                path.display()
+            ),
+            InputError::NotFound { path, day: None } => {
+                write!(f, "Input file {} does not exist", path.display())
+            }
+            InputError::Empty(source) => write!(f, "Input from {source} is empty"),
+            InputError::Io(source, err) => write!(f, "Could not read input from {source}: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_source_selection() {
+        let dir = Path::new("inputs");
+        assert_eq!(InputSource::new(Some("-"), dir, 1), InputSource::Stdin);
+        assert_eq!(
+            InputSource::new(Some("my.in"), dir, 1),
+            InputSource::File(PathBuf::from("my.in"))
+        );
+        let source = InputSource::new(None, dir, 5);
+        assert_eq!(
+            source.to_string(),
+            Path::new("inputs").join("day05.in").display().to_string()
+        );
+    }
+
+    #[test]
+    fn test_read_day() {
+        let dir = temp_dir("read");
+        fs::write(day_path(&dir, 3), "467..114..\n").unwrap();
+        let source = InputSource::Day {
+            dir: dir.clone(),
+            day: 3,
+        };
+        assert_eq!(source.read().unwrap(), "467..114..\n");
+
+        fs::write(day_path(&dir, 4), "  \n").unwrap();
+        let source = InputSource::Day {
+            dir: dir.clone(),
+            day: 4,
+        };
+        assert!(matches!(source.read(), Err(InputError::Empty(_))));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let dir = temp_dir("missing");
+        let err = InputSource::Day {
+            dir: dir.clone(),
+            day: 7,
+        }
+        .read()
+        .unwrap_err();
+        assert!(matches!(err, InputError::NotFound { day: Some(7), .. }));
+        assert!(err.to_string().starts_with("No input found for day 7"));
+
+        let err = InputSource::File(dir.join("nope.in")).read().unwrap_err();
+        assert!(matches!(err, InputError::NotFound { day: None, .. }));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,17 @@
+//! Shared building blocks for the Advent of Code 2023 solutions.
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod cycle;
+pub mod days;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod parsing;
+pub mod ranges;
+pub mod scaffold;
+pub mod solution;
+pub mod symbols;
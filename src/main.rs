@@ -0,0 +1,626 @@
+//! Command line runner for all days.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::SystemTime,
+};
+
+use aoc::answers::{self, Answers, Verdict};
+use aoc::bench::{self, Report};
+use aoc::client::{self, Client, Fetched, Outcome};
+use aoc::days;
+use aoc::input::{self, InputError, InputSource};
+use aoc::parsing::ParseError;
+use aoc::scaffold;
+
+const USAGE: &str = "Usage:
+  aoc run <day> [--part 1|2|both] [--input <path>|-] [--inputs-dir <dir>] [--save] [--answers <path>]
+  aoc verify [--inputs-dir <dir>] [--answers <path>]
+  aoc bench [<day>...] [--iterations <n>] [--inputs-dir <dir>] [--json <path>] [--baseline <path>]
+  aoc new <day>
+  aoc fetch <day>... [--inputs-dir <dir>]
+  aoc submit <day> <part> [--input <path>|-] [--inputs-dir <dir>] [--answers <path>]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    One,
+    Two,
+    Both,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct RunArgs {
+    day: u8,
+    part: Part,
+    input: Option<String>,
+    inputs_dir: Option<String>,
+    save: bool,
+    answers: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct VerifyArgs {
+    inputs_dir: Option<String>,
+    answers: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct FetchArgs {
+    days: Vec<u8>,
+    inputs_dir: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct SubmitArgs {
+    day: u8,
+    part: u8,
+    input: Option<String>,
+    inputs_dir: Option<String>,
+    answers: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct BenchArgs {
+    days: Vec<u8>,
+    iterations: usize,
+    inputs_dir: Option<String>,
+    json: Option<String>,
+    baseline: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]).and_then(run),
+        Some("verify") => parse_verify_args(&args[1..]).and_then(verify),
+        Some("bench") => parse_bench_args(&args[1..]).and_then(bench),
+        Some("fetch") => parse_fetch_args(&args[1..]).and_then(fetch),
+        Some("submit") => parse_submit_args(&args[1..]).and_then(submit),
+        Some("new") => match &args[1..] {
+            [day] => parse_day(day).and_then(new_day),
+            _ => Err(USAGE.to_string()),
+        },
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut args = args.iter();
+    let day = match args.next() {
+        Some(day) => parse_day(day)?,
+        None => return Err(USAGE.to_string()),
+    };
+    let mut part = Part::Both;
+    let mut input = None;
+    let mut inputs_dir = None;
+    let mut save = false;
+    let mut answers = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                part = match args.next().map(String::as_str) {
+                    Some("1") => Part::One,
+                    Some("2") => Part::Two,
+                    Some("both") => Part::Both,
+                    p => return Err(format!("Invalid part: {p:?}, expected 1, 2 or both")),
+                }
+            }
+            "--input" | "-i" => input = Some(flag_value(args.next(), arg)?),
+            "--inputs-dir" => inputs_dir = Some(flag_value(args.next(), arg)?),
+            "--save" => save = true,
+            "--answers" => answers = Some(flag_value(args.next(), arg)?),
+            _ => return Err(format!("Unknown argument: {arg}\n{USAGE}")),
+        }
+    }
+    Ok(RunArgs {
+        day,
+        part,
+        input,
+        inputs_dir,
+        save,
+        answers,
+    })
+}
+
+fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut args = args.iter();
+    let mut inputs_dir = None;
+    let mut answers = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--inputs-dir" => inputs_dir = Some(flag_value(args.next(), arg)?),
+            "--answers" => answers = Some(flag_value(args.next(), arg)?),
+            _ => return Err(format!("Unknown argument: {arg}\n{USAGE}")),
+        }
+    }
+    Ok(VerifyArgs {
+        inputs_dir,
+        answers,
+    })
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut args = args.iter();
+    let mut days = Vec::new();
+    let mut iterations = bench::DEFAULT_ITERATIONS;
+    let mut inputs_dir = None;
+    let mut json = None;
+    let mut baseline = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" | "-n" => {
+                iterations = match flag_value(args.next(), arg)?.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid {arg}, expected a positive number")),
+                }
+            }
+            "--inputs-dir" => inputs_dir = Some(flag_value(args.next(), arg)?),
+            "--json" => json = Some(flag_value(args.next(), arg)?),
+            "--baseline" => baseline = Some(flag_value(args.next(), arg)?),
+            day if !day.starts_with('-') => days.push(parse_day(day)?),
+            _ => return Err(format!("Unknown argument: {arg}\n{USAGE}")),
+        }
+    }
+    Ok(BenchArgs {
+        days,
+        iterations,
+        inputs_dir,
+        json,
+        baseline,
+    })
+}
+
+fn parse_fetch_args(args: &[String]) -> Result<FetchArgs, String> {
+    let mut args = args.iter();
+    let mut days = Vec::new();
+    let mut inputs_dir = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--inputs-dir" => inputs_dir = Some(flag_value(args.next(), arg)?),
+            day if !day.starts_with('-') => days.push(parse_day(day)?),
+            _ => return Err(format!("Unknown argument: {arg}\n{USAGE}")),
+        }
+    }
+    if days.is_empty() {
+        return Err(USAGE.to_string());
+    }
+    Ok(FetchArgs { days, inputs_dir })
+}
+
+fn parse_submit_args(args: &[String]) -> Result<SubmitArgs, String> {
+    let mut args = args.iter();
+    let (Some(day), Some(part)) = (args.next(), args.next()) else {
+        return Err(USAGE.to_string());
+    };
+    let day = parse_day(day)?;
+    let part = match part.as_str() {
+        "1" => 1,
+        "2" => 2,
+        p => return Err(format!("Invalid part: {p}, expected 1 or 2")),
+    };
+    let mut input = None;
+    let mut inputs_dir = None;
+    let mut answers = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => input = Some(flag_value(args.next(), arg)?),
+            "--inputs-dir" => inputs_dir = Some(flag_value(args.next(), arg)?),
+            "--answers" => answers = Some(flag_value(args.next(), arg)?),
+            _ => return Err(format!("Unknown argument: {arg}\n{USAGE}")),
+        }
+    }
+    Ok(SubmitArgs {
+        day,
+        part,
+        input,
+        inputs_dir,
+        answers,
+    })
+}
+
+fn flag_value(value: Option<&String>, flag: &str) -> Result<String, String> {
+    value
+        .cloned()
+        .ok_or_else(|| format!("Missing value after {flag}"))
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    match day.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "Invalid day: {day}, expected a number from 1 to 25"
+        )),
+    }
+}
+
+fn answers_path(arg: Option<&str>) -> PathBuf {
+    PathBuf::from(arg.unwrap_or(answers::DEFAULT_ANSWERS_FILE))
+}
+
+fn invalid_input(day: u8, err: ParseError, input: &str) -> String {
+    let message = format!("Invalid input for day {day}: {err}");
+    match err.snippet(input) {
+        Some(snippet) => format!("{message}\n{snippet}"),
+        None => message,
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let Some(solution) = days::get(args.day) else {
+        return Err(format!("Day {} is not implemented", args.day));
+    };
+    let dir = input::inputs_dir(args.inputs_dir.as_deref());
+    let input = InputSource::new(args.input.as_deref(), &dir, args.day)
+        .read()
+        .map_err(|e| e.to_string())?;
+
+    let parsed = solution
+        .prepare(&input)
+        .map_err(|e| invalid_input(args.day, e, &input))?;
+    let mut results = Vec::new();
+    if matches!(args.part, Part::One | Part::Both) {
+        results.push((1, parsed.part1()));
+    }
+    if matches!(args.part, Part::Two | Part::Both) {
+        results.push((2, parsed.part2()));
+    }
+    for (part, answer) in results.iter() {
+        println!("Day {:02} part {part}: {answer}", args.day);
+    }
+
+    if args.save {
+        let path = answers_path(args.answers.as_deref());
+        let mut answers = Answers::load(&path).map_err(|e| e.to_string())?;
+        for (part, answer) in results.iter() {
+            answers.record(args.day, &input, *part, answer);
+        }
+        answers.save(&path).map_err(|e| e.to_string())?;
+        println!("Saved to {}", path.display());
+    }
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let answers =
+        Answers::load(&answers_path(args.answers.as_deref())).map_err(|e| e.to_string())?;
+    let dir = input::inputs_dir(args.inputs_dir.as_deref());
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    for &(day, solution) in days::DAYS {
+        let input = match InputSource::new(None, &dir, day).read() {
+            Ok(input) => input,
+            Err(InputError::NotFound { path, .. }) => {
+                println!("Day {day:02}: skipped, no input at {}", path.display());
+                continue;
+            }
+            Err(err) => {
+                println!("Day {day:02}: skipped, {err}");
+                continue;
+            }
+        };
+        let parsed = match solution.prepare(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                println!("{}", invalid_input(day, err, &input));
+                failed += 2;
+                continue;
+            }
+        };
+        for (part, answer) in [(1, parsed.part1()), (2, parsed.part2())] {
+            let verdict = answers.verify(day, &input, part, &answer);
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Unknown => unknown += 1,
+            }
+            println!("Day {day:02} part {part}: {answer} {verdict}");
+        }
+    }
+    println!("{passed} passed, {failed} failed, {unknown} unknown");
+    if failed > 0 {
+        return Err(format!("{failed} answers did not match"));
+    }
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let baseline = match &args.baseline {
+        Some(path) => Some(Report::load(&PathBuf::from(path)).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let dir = input::inputs_dir(args.inputs_dir.as_deref());
+    let mut report = Report {
+        iterations: args.iterations,
+        days: Vec::new(),
+    };
+
+    println!(
+        "{} iterations, median / p95{}",
+        args.iterations,
+        if baseline.is_some() {
+            ", change in median against the baseline"
+        } else {
+            ""
+        }
+    );
+    println!(
+        "{:<6} {:>21} {:>21} {:>21}",
+        "Day", "parse", "part 1", "part 2"
+    );
+    for &(day, solution) in days::DAYS {
+        if !args.days.is_empty() && !args.days.contains(&day) {
+            continue;
+        }
+        let input = match InputSource::new(None, &dir, day).read() {
+            Ok(input) => input,
+            Err(InputError::NotFound { path, .. }) => {
+                println!("Day {day:02}: skipped, no input at {}", path.display());
+                continue;
+            }
+            Err(err) => {
+                println!("Day {day:02}: skipped, {err}");
+                continue;
+            }
+        };
+        let timings = bench::bench_day(day, solution, &input, args.iterations)
+            .map_err(|e| invalid_input(day, e, &input))?;
+        println!(
+            "Day {day:02} {:>21} {:>21} {:>21}",
+            timings.parse, timings.part1, timings.part2
+        );
+        if let Some(before) = baseline.as_ref().and_then(|b| b.get(day)) {
+            println!(
+                "{:<6} {:>21} {:>21} {:>21}",
+                "",
+                bench::change(&before.parse, &timings.parse),
+                bench::change(&before.part1, &timings.part1),
+                bench::change(&before.part2, &timings.part2)
+            );
+        }
+        report.days.push(timings);
+    }
+
+    if let Some(path) = &args.json {
+        let path = PathBuf::from(path);
+        report.save(&path).map_err(|e| e.to_string())?;
+        println!("Saved to {}", path.display());
+    }
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<(), String> {
+    let dir = input::inputs_dir(args.inputs_dir.as_deref());
+    let client = Client::from_env();
+    for day in args.days {
+        let (path, fetched) =
+            client::fetch_input(&client, &dir, day).map_err(|e| format!("Day {day:02}: {e}"))?;
+        match fetched {
+            Fetched::Cached => println!("Day {day:02}: already cached at {}", path.display()),
+            Fetched::Downloaded => println!("Day {day:02}: saved to {}", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<(), String> {
+    let Some(solution) = days::get(args.day) else {
+        return Err(format!("Day {} is not implemented", args.day));
+    };
+    let dir = input::inputs_dir(args.inputs_dir.as_deref());
+    let input = InputSource::new(args.input.as_deref(), &dir, args.day)
+        .read()
+        .map_err(|e| e.to_string())?;
+    let parsed = solution
+        .prepare(&input)
+        .map_err(|e| invalid_input(args.day, e, &input))?;
+    let answer = match args.part {
+        1 => parsed.part1(),
+        _ => parsed.part2(),
+    };
+    println!("Day {:02} part {}: {answer}", args.day, args.part);
+
+    let path = answers_path(args.answers.as_deref());
+    let mut answers = Answers::load(&path).map_err(|e| e.to_string())?;
+    let now = SystemTime::now();
+    if let Some(wait) = answers.throttled(now) {
+        return Err(format!(
+            "Not submitting, wait {}s before trying again",
+            wait.as_secs()
+        ));
+    }
+    answers
+        .check_submission(args.day, &input, args.part, &answer)
+        .map_err(|refusal| format!("Not submitting, {refusal}"))?;
+
+    let submission = Client::from_env()
+        .submit(args.day, args.part, &answer)
+        .map_err(|e| e.to_string())?;
+    println!("{}", submission.message);
+    match submission.outcome {
+        Outcome::Correct => answers.record(args.day, &input, args.part, &answer),
+        Outcome::Incorrect(why) => answers.reject(args.day, &input, args.part, &answer, why),
+        Outcome::RateLimited | Outcome::WrongLevel | Outcome::Unrecognized => (),
+    }
+    if let Some(wait) = submission.wait {
+        answers.throttle(now + wait);
+    }
+    answers.save(&path).map_err(|e| e.to_string())?;
+
+    match submission.outcome {
+        Outcome::Correct => Ok(()),
+        Outcome::Incorrect(_) => Err(format!("{answer} was rejected")),
+        Outcome::RateLimited => Err(format!("{answer} was not checked, try again later")),
+        Outcome::WrongLevel | Outcome::Unrecognized => Err(format!("{answer} was not accepted")),
+    }
+}
+
+fn new_day(day: u8) -> Result<(), String> {
+    let days_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("days");
+    let paths = scaffold::new_day(&days_dir, day).map_err(|e| e.to_string())?;
+    for path in paths {
+        println!("Wrote {}", path.display());
+    }
+    println!("Paste the example into the fixture, then start with `./test.sh day{day:02}`");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_run_args() {
+        assert_eq!(
+            parse_run_args(&args(&["5"])),
+            Ok(RunArgs {
+                day: 5,
+                part: Part::Both,
+                input: None,
+                inputs_dir: None,
+                save: false,
+                answers: None,
+            })
+        );
+        assert_eq!(
+            parse_run_args(&args(&["17", "--part", "2", "--input", "my.in"])),
+            Ok(RunArgs {
+                day: 17,
+                part: Part::Two,
+                input: Some("my.in".to_string()),
+                inputs_dir: None,
+                save: false,
+                answers: None,
+            })
+        );
+        assert_eq!(
+            parse_run_args(&args(&["1", "-i", "-", "--inputs-dir", "other"])),
+            Ok(RunArgs {
+                day: 1,
+                part: Part::Both,
+                input: Some("-".to_string()),
+                inputs_dir: Some("other".to_string()),
+                save: false,
+                answers: None,
+            })
+        );
+        assert_eq!(
+            parse_run_args(&args(&["3", "--save", "--answers", "mine.toml"])),
+            Ok(RunArgs {
+                day: 3,
+                part: Part::Both,
+                input: None,
+                inputs_dir: None,
+                save: true,
+                answers: Some("mine.toml".to_string()),
+            })
+        );
+        assert!(parse_run_args(&args(&[])).is_err());
+        assert!(parse_run_args(&args(&["26"])).is_err());
+        assert!(parse_run_args(&args(&["1", "--part", "3"])).is_err());
+        assert!(parse_run_args(&args(&["1", "--verbose"])).is_err());
+        assert!(parse_run_args(&args(&["1", "--answers"])).is_err());
+        assert_eq!(
+            parse_run_args(&args(&["1", "--input"])),
+            Err("Missing value after --input".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_verify_args() {
+        assert_eq!(
+            parse_verify_args(&args(&[])),
+            Ok(VerifyArgs {
+                inputs_dir: None,
+                answers: None,
+            })
+        );
+        assert_eq!(
+            parse_verify_args(&args(&["--answers", "a.toml", "--inputs-dir", "in"])),
+            Ok(VerifyArgs {
+                inputs_dir: Some("in".to_string()),
+                answers: Some("a.toml".to_string()),
+            })
+        );
+        assert!(parse_verify_args(&args(&["5"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_fetch_args() {
+        assert_eq!(
+            parse_fetch_args(&args(&["1", "2", "--inputs-dir", "in"])),
+            Ok(FetchArgs {
+                days: vec![1, 2],
+                inputs_dir: Some("in".to_string()),
+            })
+        );
+        assert!(parse_fetch_args(&args(&[])).is_err());
+        assert!(parse_fetch_args(&args(&["0"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_submit_args() {
+        assert_eq!(
+            parse_submit_args(&args(&["5", "2", "--answers", "a.toml"])),
+            Ok(SubmitArgs {
+                day: 5,
+                part: 2,
+                input: None,
+                inputs_dir: None,
+                answers: Some("a.toml".to_string()),
+            })
+        );
+        assert!(parse_submit_args(&args(&["5"])).is_err());
+        assert!(parse_submit_args(&args(&["5", "both"])).is_err());
+        assert!(parse_submit_args(&args(&["5", "1", "--save"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        assert_eq!(
+            parse_bench_args(&args(&[])),
+            Ok(BenchArgs {
+                days: vec![],
+                iterations: bench::DEFAULT_ITERATIONS,
+                inputs_dir: None,
+                json: None,
+                baseline: None,
+            })
+        );
+        assert_eq!(
+            parse_bench_args(&args(&[
+                "6",
+                "9",
+                "-n",
+                "50",
+                "--json",
+                "new.json",
+                "--baseline",
+                "old.json"
+            ])),
+            Ok(BenchArgs {
+                days: vec![6, 9],
+                iterations: 50,
+                inputs_dir: None,
+                json: Some("new.json".to_string()),
+                baseline: Some("old.json".to_string()),
+            })
+        );
+        assert!(parse_bench_args(&args(&["--iterations", "0"])).is_err());
+        assert!(parse_bench_args(&args(&["30"])).is_err());
+        assert!(parse_bench_args(&args(&["--fast"])).is_err());
+    }
+}
@@ -0,0 +1,44 @@
+//! Number theory helpers.
+
+use num::{Integer, Signed};
+
+/// The least common multiple of all values, or `1` for an empty iterator.
+pub fn lcm_all<T: Integer + Copy>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::one(), |acc, v| acc.lcm(&v))
+}
+
+/// Combine the congruences `x ≡ a (mod m)` and `x ≡ b (mod n)` into one, `x ≡ c (mod lcm(m, n))`,
+/// with `c` between zero and the new modulus. The moduli don't need to be coprime, but then the
+/// congruences can contradict each other, in which case this returns `None`.
+///
+/// Intermediate values go up to the square of the new modulus, which has to fit in `T`.
+pub fn crt<T: Integer + Signed + Copy>((a, m): (T, T), (b, n): (T, T)) -> Option<(T, T)> {
+    let gcd = m.extended_gcd(&n);
+    if !(b - a).is_multiple_of(&gcd.gcd) {
+        return None;
+    }
+    // a + m * t satisfies both when m * t ≡ b - a (mod n), so t = (b - a) / gcd * x (mod n / gcd)
+    let step = n / gcd.gcd;
+    let t = ((b - a) / gcd.gcd).mod_floor(&step) * gcd.x.mod_floor(&step) % step;
+    let modulus = m * step;
+    Some(((a + m * t).mod_floor(&modulus), modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lcm_all() {
+        assert_eq!(lcm_all([2_u64, 3, 4]), 12);
+        assert_eq!(lcm_all(Vec::<usize>::new()), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt((2_i64, 3), (3, 5)), Some((8, 15)));
+        assert_eq!(crt((1_i64, 4), (3, 6)), Some((9, 12)));
+        assert_eq!(crt((0_i64, 4), (1, 6)), None);
+        assert_eq!(crt((-1_i64, 7), (0, 1)), Some((6, 7)));
+    }
+}
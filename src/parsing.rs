@@ -0,0 +1,191 @@
+//! Small parsing helpers shared between the days, and the error they report.
+
+use std::{fmt, str::FromStr};
+
+/// A problem in the puzzle input, pointing at the offending token.
+///
+/// Parsers create the error from the token they choke on, which is a slice of the input. The
+/// line and column are filled in afterwards by [`ParseError::locate`], so parsers working on a
+/// single line or block don't need to know where it sits in the whole input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the offending token, starting at 1. Zero while the error isn't located yet.
+    pub line: usize,
+    /// Column of the offending token, starting at 1.
+    pub column: usize,
+    /// What the parser expected to find.
+    pub expected: String,
+    /// The text it found instead, empty at the end of a line or the input.
+    pub found: String,
+    address: usize,
+}
+
+impl ParseError {
+    /// An error at `token`, which should be a slice of the puzzle input.
+    pub fn new(token: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            line: 0,
+            column: 0,
+            expected: expected.into(),
+            found: token.to_string(),
+            address: token.as_ptr() as usize,
+        }
+    }
+
+    /// An error just past the end of `s`, for when a token is missing.
+    pub fn after(s: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(&s[s.len()..], expected)
+    }
+
+    /// Fill in the line and column of the token in `input`. Leaves the error untouched if the
+    /// token isn't part of `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.line != 0 || self.address < start || self.address > start + input.len() {
+            return self;
+        }
+        let before = &input[..self.address - start];
+        self.line = before.matches('\n').count() + 1;
+        self.column = before
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count()
+            + 1;
+        self
+    }
+
+    /// The offending line of `input` with a marker under the token, if the error is located.
+    pub fn snippet(&self, input: &str) -> Option<String> {
+        let line = input.lines().nth(self.line.checked_sub(1)?)?;
+        let gutter = self.line.to_string().len();
+        let width = self.found.chars().count().max(1);
+        Some(format!(
+            "{:gutter$} |\n{} | {line}\n{:gutter$} | {}{}",
+            "",
+            self.line,
+            "",
+            " ".repeat(self.column - 1),
+            "^".repeat(width),
+        ))
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line != 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+        match self.found.as_str() {
+            "" => write!(f, "expected {}, found nothing", self.expected),
+            found => write!(f, "expected {}, found `{found}`", self.expected),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse a single number.
+pub fn number<T: FromStr>(token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(token, "a number"))
+}
+
+/// Parse a whitespace separated list of numbers, failing on anything that isn't a number.
+pub fn number_list<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError> {
+    input.split_whitespace().map(number).collect()
+}
+
+/// Split `s` around the first `delimiter`, failing if it doesn't contain one.
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::after(s, format!("`{delimiter}`")))
+}
+
+/// Strip `prefix` from `s`, failing if `s` doesn't start with it.
+pub fn prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        let end = s
+            .char_indices()
+            .nth(prefix.chars().count())
+            .map_or(s.len(), |(i, _)| i);
+        ParseError::new(&s[..end], format!("`{prefix}`"))
+    })
+}
+
+/// Parse all whitespace separated numbers in a string, skipping anything that isn't a number.
+pub fn numbers<T: FromStr>(input: &str) -> impl Iterator<Item = T> + Clone + '_ {
+    input.split_whitespace().filter_map(|s| s.parse().ok())
+}
+
+/// Split the input into blocks separated by empty lines. Every line is trimmed.
+pub fn paragraphs(input: &str) -> Vec<Vec<&str>> {
+    let mut result = Vec::new();
+    let mut curr = Vec::new();
+    for line in input.trim().lines().map(str::trim) {
+        if line.is_empty() {
+            result.push(std::mem::take(&mut curr));
+        } else {
+            curr.push(line);
+        }
+    }
+    if !curr.is_empty() {
+        result.push(curr);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(
+            numbers::<i64>("0  3 -6 x 9").collect::<Vec<_>>(),
+            vec![0, 3, -6, 9]
+        );
+        assert_eq!(
+            numbers::<u32>("Time:      7  15   30").collect::<Vec<_>>(),
+            vec![7, 15, 30]
+        );
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "1 2 3\n4 x 6\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = number_list::<u32>(line).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a number, found `x`"
+        );
+        assert_eq!(err.snippet(input).unwrap(), "  |\n2 | 4 x 6\n  |   ^");
+
+        let err = split_once(line, "|").unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected `|`, found nothing"
+        );
+
+        let err = prefix(line, "Game ").unwrap_err();
+        assert_eq!(err.found, "4 x 6");
+        // Tokens from somewhere else can't be located
+        assert_eq!(err.locate("Game 1").line, 0);
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let input = r#"
+            a
+            b
+
+            c
+        "#;
+        assert_eq!(paragraphs(input), vec![vec!["a", "b"], vec!["c"]]);
+    }
+}
@@ -0,0 +1,298 @@
+//! Sets of integers stored as ranges, for puzzles working on spans of values far too large to
+//! go through one by one.
+//!
+//! All ranges are half-open, like [`Range`]: `start` is included, `end` isn't.
+
+use std::ops::Range;
+
+use num::PrimInt;
+
+/// A set of integers, stored as sorted and disjoint ranges. Ranges that touch are merged, so two
+/// sets with the same values always compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: PrimInt> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    /// The ranges in the set, in increasing order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The amount of values in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |len, range| len + (range.end - range.start))
+    }
+
+    /// The lowest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+
+    /// Add all values of `range` to the set.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let mut merged = range;
+        let mut placed = false;
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        for range in self.ranges.drain(..) {
+            if range.end < merged.start {
+                ranges.push(range);
+            } else if range.start > merged.end {
+                if !placed {
+                    ranges.push(merged.clone());
+                    placed = true;
+                }
+                ranges.push(range);
+            } else {
+                merged = merged.start.min(range.start)..merged.end.max(range.end);
+            }
+        }
+        if !placed {
+            ranges.push(merged);
+        }
+        self.ranges = ranges;
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        for range in &self.ranges {
+            for overlap in other.overlapping(range) {
+                ranges.push(range.start.max(overlap.start)..range.end.min(overlap.end));
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// The values in this set that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        for range in &self.ranges {
+            let mut start = range.start;
+            for overlap in other.overlapping(range) {
+                if overlap.start > start {
+                    ranges.push(start..overlap.start);
+                }
+                start = start.max(overlap.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// Split the set into the values below `at` and the values from `at` on.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+        for range in &self.ranges {
+            if range.end <= at {
+                below.push(range.clone());
+            } else if range.start >= at {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..at);
+                above.push(at..range.end);
+            }
+        }
+        (RangeSet { ranges: below }, RangeSet { ranges: above })
+    }
+
+    /// Move every value by the offset from `from` to `to`, so `from` itself would end up at `to`.
+    pub fn translate(&self, from: T, to: T) -> Self {
+        let shift = |value: T| {
+            if to >= from {
+                value + (to - from)
+            } else {
+                value - (from - to)
+            }
+        };
+        RangeSet {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|range| shift(range.start)..shift(range.end))
+                .collect(),
+        }
+    }
+
+    /// The ranges of this set that overlap with `range`.
+    fn overlapping<'a>(&'a self, range: &'a Range<T>) -> impl Iterator<Item = &'a Range<T>> {
+        self.ranges
+            .iter()
+            .skip_while(move |other| other.end <= range.start)
+            .take_while(move |other| other.start < range.end)
+    }
+}
+
+impl<T: PrimInt> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = RangeSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: PrimInt> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// An N-dimensional box of integers: the values between a pair of bounds along each axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HyperRect<T, const N: usize> {
+    pub axes: [Range<T>; N],
+}
+
+impl<T: PrimInt, const N: usize> HyperRect<T, N> {
+    pub fn new(axes: [Range<T>; N]) -> Self {
+        HyperRect { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Range::is_empty)
+    }
+
+    /// The amount of points in the box.
+    pub fn volume(&self) -> u128 {
+        self.axes
+            .iter()
+            .map(|range| {
+                if range.is_empty() {
+                    0
+                } else {
+                    (range.end - range.start).to_u128().unwrap()
+                }
+            })
+            .product()
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(range, value)| range.contains(value))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes.clone();
+        for (range, other) in axes.iter_mut().zip(&other.axes) {
+            *range = range.start.max(other.start)..range.end.min(other.end);
+        }
+        let rect = HyperRect { axes };
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    /// Cut the box in two along `axis`: the part below `at` and the part from `at` on. Either
+    /// can be empty.
+    pub fn split_at(&self, axis: usize, at: T) -> (Self, Self) {
+        let mut below = self.clone();
+        let mut above = self.clone();
+        let range = &self.axes[axis];
+        let at = at.max(range.start).min(range.end);
+        below.axes[axis] = range.start..at;
+        above.axes[axis] = at..range.end;
+        (below, above)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<u32>]) -> RangeSet<u32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut ranges = set(&[10..20, 30..40]);
+        ranges.insert(0..5);
+        ranges.insert(20..25);
+        ranges.insert(50..50);
+        assert_eq!(ranges.ranges(), &[0..5, 10..25, 30..40]);
+        ranges.insert(3..35);
+        assert_eq!(ranges, RangeSet::from(0..40));
+        assert_eq!(ranges.len(), 40);
+        assert_eq!(set(&[5..8, 1..3]).min(), Some(1));
+        assert!(RangeSet::<u32>::new().is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = RangeSet::from(5..25);
+        assert_eq!(a.union(&b), RangeSet::from(0..30));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..30]));
+        assert_eq!(b.difference(&a), RangeSet::from(10..20));
+        assert!(a.contains(29));
+        assert!(!a.contains(15));
+    }
+
+    #[test]
+    fn test_split_and_translate() {
+        let a = set(&[0..10, 20..30]);
+        assert_eq!(
+            a.split_at(25),
+            (set(&[0..10, 20..25]), RangeSet::from(25..30))
+        );
+        assert_eq!(
+            a.split_at(15),
+            (RangeSet::from(0..10), RangeSet::from(20..30))
+        );
+        assert_eq!(a.translate(20, 50), set(&[30..40, 50..60]));
+        assert_eq!(set(&[10..20, 30..40]).translate(10, 0), a);
+    }
+
+    #[test]
+    fn test_hyper_rect() {
+        let cube = HyperRect::new([0..10, 0..10, 0..10]);
+        assert_eq!(cube.volume(), 1000);
+        assert!(cube.contains(&[0, 5, 9]));
+        assert!(!cube.contains(&[0, 5, 10]));
+
+        let (below, above) = cube.split_at(1, 4);
+        assert_eq!(below.axes, [0..10, 0..4, 0..10]);
+        assert_eq!(above.volume(), 600);
+        let (below, above) = cube.split_at(0, 20);
+        assert_eq!((below.volume(), above.is_empty()), (1000, true));
+
+        let other = HyperRect::new([5..15, 5..15, 9..20]);
+        assert_eq!(
+            cube.intersection(&other),
+            Some(HyperRect::new([5..10, 5..10, 9..10]))
+        );
+        assert_eq!(
+            cube.intersection(&HyperRect::new([10..20, 0..1, 0..1])),
+            None
+        );
+    }
+}
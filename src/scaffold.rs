@@ -0,0 +1,266 @@
+//! Generating the boilerplate for a new day: the module, its registration in `days/mod.rs` and
+//! an example-input fixture for its tests.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day already has a module or a registry entry.
+    Exists(u8),
+    /// `days/mod.rs` doesn't look like expected, so the day can't be registered in it.
+    Unregistrable(String),
+    Io(PathBuf, io::Error),
+}
+
+/// Generate everything needed for `day` under `days_dir` (usually `src/days`), returning the
+/// paths that were created or changed.
+pub fn new_day(days_dir: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let module = days_dir.join(format!("day{day:02}.rs"));
+    let fixture = days_dir.join("fixtures").join(format!("day{day:02}.txt"));
+    let registry = days_dir.join("mod.rs");
+    if module.exists() {
+        return Err(ScaffoldError::Exists(day));
+    }
+
+    let mod_rs = read(&registry)?;
+    let mod_rs = register(&mod_rs, day)?;
+    write(&module, &module_template(day))?;
+    if !fixture.exists() {
+        write(&fixture, "")?;
+    }
+    write(&registry, &mod_rs)?;
+    Ok(vec![module, fixture, registry])
+}
+
+/// The source of a new day's module, with stub parts and failing example tests.
+pub fn module_template(day: u8) -> String {
+    MODULE_TEMPLATE.replace("{day}", &format!("{day:02}"))
+}
+
+const MODULE_TEMPLATE: &str = r#"#![allow(unused)]
+
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        run1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        run2(input).into()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.trim().lines().map(|line| line.trim().to_string()).collect())
+}
+
+fn run1(lines: &[String]) -> u64 {
+    todo!()
+}
+
+fn run2(lines: &[String]) -> u64 {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("fixtures/day{day}.txt");
+
+    #[test]
+    fn test_day{day}p1_example() {
+        // TODO: paste the example into fixtures/day{day}.txt and fill in its answer
+        assert_eq!(run1(&parse_input(EXAMPLE).unwrap()), 0);
+    }
+
+    #[test]
+    fn test_day{day}p2_example() {
+        assert_eq!(run2(&parse_input(EXAMPLE).unwrap()), 0);
+    }
+}
+"#;
+
+/// Add `day` to the contents of `days/mod.rs`: a `pub mod` declaration and an entry in `DAYS`,
+/// both kept in day order.
+pub fn register(mod_rs: &str, day: u8) -> Result<String, ScaffoldError> {
+    let declaration = format!("pub mod day{day:02};");
+    let entry = format!("    ({day}, &day{day:02}::Day{day:02}),");
+    let mut lines: Vec<String> = mod_rs.lines().map(str::to_string).collect();
+
+    let declared = |line: &str| {
+        line.strip_prefix("pub mod day")
+            .and_then(|rest| rest.strip_suffix(';'))
+            .and_then(|n| n.parse::<u8>().ok())
+    };
+    let Some(at) = insertion_point(&lines, day, declared) else {
+        return Err(ScaffoldError::Unregistrable(
+            "no `pub mod dayXX;` declarations found".to_string(),
+        ));
+    };
+    if lines.contains(&declaration) {
+        return Err(ScaffoldError::Exists(day));
+    }
+    lines.insert(at, declaration);
+
+    let registered = |line: &str| {
+        line.trim()
+            .strip_prefix('(')
+            .and_then(|rest| rest.split_once(", &day"))
+            .and_then(|(n, _)| n.parse::<u8>().ok())
+    };
+    let Some(at) = insertion_point(&lines, day, registered) else {
+        return Err(ScaffoldError::Unregistrable(
+            "no entries found in `DAYS`".to_string(),
+        ));
+    };
+    if lines.iter().any(|line| registered(line) == Some(day)) {
+        return Err(ScaffoldError::Exists(day));
+    }
+    lines.insert(at, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Where to insert `day` among the lines `key` recognises: right before the first later day, or
+/// after the last one. `None` if `key` recognises no lines at all.
+fn insertion_point(lines: &[String], day: u8, key: impl Fn(&str) -> Option<u8>) -> Option<usize> {
+    let matching: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| key(line).map(|d| (i, d)))
+        .collect();
+    let (last, _) = *matching.last()?;
+    Some(
+        matching
+            .iter()
+            .find(|(_, d)| *d > day)
+            .map_or(last + 1, |(i, _)| *i),
+    )
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| ScaffoldError::Io(dir.to_path_buf(), e))?;
+    }
+    fs::write(path, content).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e))
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(day) => write!(f, "Day {day} already exists"),
+            ScaffoldError::Unregistrable(reason) => {
+                write!(f, "Could not register the day in days/mod.rs: {reason}")
+            }
+            ScaffoldError::Io(path, err) => write!(f, "Could not access {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "use crate::solution::AnySolution;
+
+pub mod day01;
+pub mod day11;
+pub mod day13;
+
+pub const DAYS: &[(u8, &dyn AnySolution)] = &[
+    (1, &day01::Day01),
+    (11, &day11::Day11),
+    (13, &day13::Day13),
+];
+";
+
+    #[test]
+    fn test_register() {
+        let registered = register(MOD_RS, 12).unwrap();
+        assert_eq!(
+            registered,
+            "use crate::solution::AnySolution;
+
+pub mod day01;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+
+pub const DAYS: &[(u8, &dyn AnySolution)] = &[
+    (1, &day01::Day01),
+    (11, &day11::Day11),
+    (12, &day12::Day12),
+    (13, &day13::Day13),
+];
+"
+        );
+
+        let registered = register(MOD_RS, 23).unwrap();
+        assert!(registered.contains("pub mod day13;\npub mod day23;\n"));
+        assert!(registered.contains("(13, &day13::Day13),\n    (23, &day23::Day23),\n"));
+    }
+
+    #[test]
+    fn test_register_errors() {
+        assert!(matches!(
+            register(MOD_RS, 11),
+            Err(ScaffoldError::Exists(11))
+        ));
+        assert!(matches!(
+            register("pub const DAYS: &[u8] = &[];", 1),
+            Err(ScaffoldError::Unregistrable(_))
+        ));
+    }
+
+    #[test]
+    fn test_module_template() {
+        let module = module_template(5);
+        assert!(module.contains("pub struct Day05;"));
+        assert!(module.contains("include_str!(\"fixtures/day05.txt\")"));
+        assert!(module.contains("fn test_day05p1_example()"));
+        assert!(!module.contains("{day}"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("mod.rs"), MOD_RS).unwrap();
+
+        let created = new_day(&dir, 12).unwrap();
+        assert_eq!(created.len(), 3);
+        assert!(fs::read_to_string(dir.join("day12.rs"))
+            .unwrap()
+            .contains("pub struct Day12;"));
+        assert_eq!(
+            fs::read_to_string(dir.join("fixtures/day12.txt")).unwrap(),
+            ""
+        );
+        assert!(fs::read_to_string(dir.join("mod.rs"))
+            .unwrap()
+            .contains("pub mod day12;"));
+        assert!(matches!(new_day(&dir, 12), Err(ScaffoldError::Exists(12))));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,172 @@
+//! The interface every day implements so the runner can find and run it.
+
+use std::fmt;
+
+use num::BigInt;
+
+use crate::parsing::ParseError;
+
+/// A solution for a single day of the puzzle.
+pub trait Solution {
+    /// The puzzle input after parsing, shared by both parts.
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(&self, input: &Self::Input) -> Answer;
+    fn part2(&self, input: &Self::Input) -> Answer;
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(BigInt),
+    Text(String),
+}
+
+/// A day's input, parsed and ready to solve either part.
+pub trait Parsed {
+    fn part1(&self) -> Answer;
+    fn part2(&self) -> Answer;
+}
+
+/// Object-safe form of [`Solution`], so days with different input types can share one registry.
+pub trait AnySolution {
+    /// Parse the input, reporting errors with their line and column in `input`.
+    fn prepare(&self, input: &str) -> Result<Box<dyn Parsed + '_>, ParseError>;
+}
+
+struct Prepared<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<S: Solution> Parsed for Prepared<'_, S> {
+    fn part1(&self) -> Answer {
+        self.solution.part1(&self.input)
+    }
+
+    fn part2(&self) -> Answer {
+        self.solution.part2(&self.input)
+    }
+}
+
+impl<S: Solution> AnySolution for S {
+    fn prepare(&self, input: &str) -> Result<Box<dyn Parsed + '_>, ParseError> {
+        let parsed = self.parse(input).map_err(|e| e.locate(input))?;
+        Ok(Box::new(Prepared {
+            solution: self,
+            input: parsed,
+        }))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::BigInteger(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! impl_from_small_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Integer(i64::from(n))
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_big_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match i64::try_from(n) {
+                    Ok(n) => Answer::Integer(n),
+                    Err(_) => Answer::BigInteger(BigInt::from(n)),
+                }
+            }
+        })*
+    };
+}
+
+impl_from_small_int!(i8, i16, i32, i64, u8, u16, u32);
+impl_from_big_int!(u64, usize, i128, u128, isize);
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        match i64::try_from(&n) {
+            Ok(n) => Answer::Integer(n),
+            Err(_) => Answer::BigInteger(n),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_from() {
+        assert_eq!(Answer::from(42u32), Answer::Integer(42));
+        assert_eq!(Answer::from(-3i64), Answer::Integer(-3));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::BigInteger(BigInt::from(u64::MAX))
+        );
+        assert_eq!(Answer::from(BigInt::from(7)), Answer::Integer(7));
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".to_string()));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input = Vec<String>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .map(|line| match line.trim() {
+                    "" => Err(ParseError::new(line, "a letter")),
+                    line => Ok(line.to_string()),
+                })
+                .collect()
+        }
+
+        fn part1(&self, input: &Self::Input) -> Answer {
+            input.len().into()
+        }
+
+        fn part2(&self, input: &Self::Input) -> Answer {
+            input.concat().into()
+        }
+    }
+
+    #[test]
+    fn test_prepare() {
+        let solution: &dyn AnySolution = &Lines;
+        let parsed = solution.prepare("a\nb").unwrap();
+        assert_eq!(parsed.part1(), Answer::Integer(2));
+        assert_eq!(parsed.part2(), Answer::Text("ab".to_string()));
+
+        let err = solution.prepare("a\n\nb").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,73 @@
+//! Dense numbers for the names in an input, so graphs of named nodes can be stored in vectors
+//! indexed by node instead of maps keyed by strings.
+
+use std::collections::HashMap;
+
+/// Numbers names in the order they are first seen, starting from 0.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Interner {
+    ids: HashMap<String, u32>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Interner::default()
+    }
+
+    /// The number of `name`, giving it the next free one if it doesn't have one yet.
+    pub fn intern(&mut self, name: &str) -> u32 {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = u32::try_from(self.names.len()).expect("too many names to number");
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+        id
+    }
+
+    /// The number of `name`, if it has one.
+    pub fn get(&self, name: &str) -> Option<u32> {
+        self.ids.get(name).copied()
+    }
+
+    /// The name with number `id`. Panics if no name has that number.
+    pub fn name(&self, id: u32) -> &str {
+        &self.names[id as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// All numbers with their name, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &str)> {
+        (0..).zip(self.names.iter().map(String::as_str))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let mut names = Interner::new();
+        assert!(names.is_empty());
+        assert_eq!(names.intern("AAA"), 0);
+        assert_eq!(names.intern("BBB"), 1);
+        assert_eq!(names.intern("AAA"), 0);
+        assert_eq!(names.len(), 2);
+        assert_eq!(names.get("BBB"), Some(1));
+        assert_eq!(names.get("CCC"), None);
+        assert_eq!(names.name(1), "BBB");
+        assert_eq!(
+            names.iter().collect::<Vec<_>>(),
+            vec![(0, "AAA"), (1, "BBB")]
+        );
+    }
+}